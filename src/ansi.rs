@@ -0,0 +1,152 @@
+use core::fmt::Write;
+
+use crate::SliceDisplayImpl;
+
+/// An ANSI text style, written as a Select Graphic Rendition escape code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ansi(&'static str);
+
+impl Ansi {
+    /// Bold text.
+    pub const BOLD: Self = Self("\x1b[1m");
+    /// Dimmed text.
+    pub const DIM: Self = Self("\x1b[2m");
+    /// Italic text.
+    pub const ITALIC: Self = Self("\x1b[3m");
+    /// Underlined text.
+    pub const UNDERLINE: Self = Self("\x1b[4m");
+    /// Red text.
+    pub const RED: Self = Self("\x1b[31m");
+    /// Green text.
+    pub const GREEN: Self = Self("\x1b[32m");
+    /// Yellow text.
+    pub const YELLOW: Self = Self("\x1b[33m");
+    /// Blue text.
+    pub const BLUE: Self = Self("\x1b[34m");
+    /// Magenta text.
+    pub const MAGENTA: Self = Self("\x1b[35m");
+    /// Cyan text.
+    pub const CYAN: Self = Self("\x1b[36m");
+
+    /// Creates a style from a complete SGR escape code, such as
+    /// `"\x1b[1;31m"` for bold red.
+    pub const fn new(escape: &'static str) -> Self {
+        Self(escape)
+    }
+
+    pub(crate) fn paint<W: Write>(
+        self,
+        out: &mut W,
+        write: impl FnOnce(&mut W) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        out.write_str(self.0)?;
+        write(out)?;
+        out.write_str("\x1b[0m")
+    }
+}
+
+/// Picks the style of each element, see [`SliceDisplayImpl::paint_by`].
+pub(crate) type PaintFn<'a, T> = &'a dyn Fn(&T) -> Option<Ansi>;
+
+/// The styles of each part of a display, see
+/// [`SliceDisplayImpl::paint_elements`].
+#[derive(Clone, Copy, Default)]
+pub(crate) struct Palette {
+    pub(crate) terminators: Option<Ansi>,
+    pub(crate) delimiters: Option<Ansi>,
+    pub(crate) indices: Option<Ansi>,
+    pub(crate) elements: Option<Ansi>,
+    pub(crate) disabled: bool,
+}
+
+impl<'a, T, F> SliceDisplayImpl<'a, T, F> {
+    /// Styles each element with the style returned by `paint`, on top of
+    /// the one set by [`paint_elements`](Self::paint_elements). Replaces any
+    /// function set before.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::{Ansi, SliceDisplay};
+    ///
+    /// let deltas = [3, -1];
+    ///
+    /// assert_eq!(
+    ///     deltas
+    ///         .display()
+    ///         .paint_by(&|delta| if *delta < 0 { Some(Ansi::RED) } else { None })
+    ///         .to_string(),
+    ///     "[3, \x1b[31m-1\x1b[0m]"
+    /// );
+    /// ```
+    pub fn paint_by(mut self, paint: PaintFn<'a, T>) -> Self {
+        self.paint = Some(paint);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString, vec};
+
+    use crate::{Ansi, Quote, SliceDisplay};
+
+    extern crate alloc;
+
+    #[test]
+    fn ansi_display() {
+        let numbers = [1, -2, 3];
+        let negative = |n: &i32| if *n < 0 { Some(Ansi::RED) } else { None };
+        assert_eq!(
+            numbers
+                .display()
+                .paint_elements(Ansi::BOLD)
+                .paint_by(&negative)
+                .wrap(8)
+                .indent(1)
+                .to_string(),
+            "[\x1b[1m1\x1b[0m, \x1b[1m\x1b[31m-2\x1b[0m\x1b[0m,\n \x1b[1m3\x1b[0m]"
+        );
+        assert_eq!(
+            numbers
+                .display()
+                .with_indices()
+                .paint_indices(Ansi::new("\x1b[2;36m"))
+                .pretty(true)
+                .trailing_delimiter(false)
+                .to_string(),
+            "[\n    \x1b[2;36m0: \x1b[0m1,\n    \x1b[2;36m1: \x1b[0m-2,\n    \x1b[2;36m2: \x1b[0m3\n]"
+        );
+        assert_eq!(
+            format!("{:#}", numbers.display().paint_by(&negative).indent(1)),
+            "[\n 1,\n -2,\n 3,\n]"
+        );
+        assert_eq!(
+            numbers
+                .display()
+                .paint_by(&negative)
+                .paint_terminators(Ansi::DIM)
+                .no_color()
+                .to_string(),
+            "[1, -2, 3]"
+        );
+
+        let words = ["a", "b"];
+        let first = |word: &&str| (*word == "a").then(|| Ansi::BOLD);
+        assert_eq!(
+            words
+                .display()
+                .paint_by(&first)
+                .quote(Quote::Rust)
+                .to_string(),
+            "[\x1b[1m\"a\"\x1b[0m, \"b\"]"
+        );
+        assert_eq!(
+            vec![vec![1, -2]]
+                .display()
+                .nested(|row| row.paint_by(&negative))
+                .to_string(),
+            "[[1, \x1b[31m-2\x1b[0m]]"
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use core::fmt::{Arguments, Display, Formatter};
+
+use crate::SliceDisplay;
+
+/// Amount of elements shown on each side of the first difference.
+const WINDOW: usize = 3;
+
+/// Asserts that two slices are equal, like [`assert_eq!`], but reports
+/// where they differ instead of printing them whole.
+///
+/// Anything that can be sliced with `[..]` can be compared, as long as its
+/// elements implement [`PartialEq`] and [`Display`].
+///
+/// # Example
+///
+/// ```rust
+/// use slicedisplay::assert_slice_eq;
+///
+/// let expected: Vec<u32> = (0..1000).collect();
+/// let mut actual = expected.clone();
+///
+/// assert_slice_eq!(actual, expected);
+///
+/// actual[500] = 0;
+/// let message = std::panic::catch_unwind(|| assert_slice_eq!(actual, expected, "run {}", 1))
+///     .unwrap_err()
+///     .downcast::<String>()
+///     .unwrap();
+/// assert_eq!(
+///     *message,
+///     "assertion `left == right` failed: run 1\n\
+///      first difference at index 500, 1 of 1000 positions differ\n \
+///      left (len 1000): [497: 497, 498: 498, 499: 499, 500: 0, 501: 501, 502: 502, 503: 503]\n\
+///      right (len 1000): [497: 497, 498: 498, 499: 499, 500: 500, 501: 501, 502: 502, 503: 503]"
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_slice_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left[..], &$right[..]) {
+            (left, right) => {
+                if left != right {
+                    $crate::__private::assert_slice_failed(left, right, None);
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left[..], &$right[..]) {
+            (left, right) => {
+                if left != right {
+                    $crate::__private::assert_slice_failed(
+                        left,
+                        right,
+                        Some(format_args!($($arg)+)),
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Describes how two slices differ, for [`assert_slice_eq!`].
+pub(crate) struct Mismatch<'a, T> {
+    pub(crate) left: &'a [T],
+    pub(crate) right: &'a [T],
+}
+
+impl<T: PartialEq + Display> Display for Mismatch<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let (left, right) = (self.left, self.right);
+        let common = left.len().min(right.len());
+        let first = left
+            .iter()
+            .zip(right)
+            .position(|(l, r)| l != r)
+            .unwrap_or(common);
+        let differing = left.iter().zip(right).filter(|(l, r)| l != r).count()
+            + (left.len().max(right.len()) - common);
+
+        writeln!(
+            f,
+            "first difference at index {first}, {differing} of {} positions differ",
+            left.len().max(right.len())
+        )?;
+
+        let start = first.saturating_sub(WINDOW);
+        let end = first.saturating_add(WINDOW + 1);
+        let left_window = left.get(start..end.min(left.len())).unwrap_or(&[]);
+        let right_window = right.get(start..end.min(right.len())).unwrap_or(&[]);
+        writeln!(
+            f,
+            " left (len {}): {}",
+            left.len(),
+            left_window.display().with_indices().index_offset(start)
+        )?;
+        write!(
+            f,
+            "right (len {}): {}",
+            right.len(),
+            right_window.display().with_indices().index_offset(start)
+        )
+    }
+}
+
+/// Panics with the report of [`assert_slice_eq!`].
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_slice_failed<T: PartialEq + Display>(
+    left: &[T],
+    right: &[T],
+    args: Option<Arguments<'_>>,
+) -> ! {
+    let mismatch = Mismatch { left, right };
+    match args {
+        Some(args) => panic!("assertion `left == right` failed: {args}\n{mismatch}"),
+        None => panic!("assertion `left == right` failed\n{mismatch}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec};
+
+    use super::Mismatch;
+
+    extern crate alloc;
+
+    #[test]
+    fn mismatch_display() {
+        let left = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let right = [1, 2, 30, 4, 5, 6, 7, 8, 9, 10, 11];
+        assert_eq!(
+            Mismatch {
+                left: &left,
+                right: &right
+            }
+            .to_string(),
+            "first difference at index 2, 3 of 11 positions differ\n \
+             left (len 9): [0: 1, 1: 2, 2: 3, 3: 4, 4: 5, 5: 6]\n\
+             right (len 11): [0: 1, 1: 2, 2: 30, 3: 4, 4: 5, 5: 6]"
+        );
+
+        assert_slice_eq!(left, left.clone());
+        assert_slice_eq!(&right[..2], [1, 2], "prefix");
+    }
+
+    #[test]
+    #[should_panic(expected = "first difference at index 0, 1 of 1 positions differ")]
+    fn assert_slice_eq_length() {
+        let empty: [&str; 0] = [];
+        assert_slice_eq!(empty, ["a"]);
+    }
+}
@@ -0,0 +1,307 @@
+use core::{
+    fmt::{Display, Formatter, Write},
+    marker::PhantomData,
+};
+
+use crate::{spec::Spec, ElementFormat, SliceDisplayImpl, WithDisplay};
+
+/// Displays a slice as a single CSV record, see [`SliceDisplayImpl::csv`].
+#[derive(Clone, Copy)]
+pub struct CsvRecord<'a, T, F> {
+    slice: &'a [T],
+    format: F,
+    delimiter: char,
+    line_terminator: &'a str,
+}
+
+/// Displays a slice of slices as a CSV document, one record per inner
+/// slice, see [`SliceDisplayImpl::csv_document`].
+#[derive(Clone, Copy)]
+pub struct CsvDocument<'a, C, U> {
+    rows: &'a [C],
+    delimiter: char,
+    line_terminator: &'a str,
+    _fields: PhantomData<fn(&U)>,
+}
+
+impl<'a, T, F> SliceDisplayImpl<'a, T, F> {
+    /// Displays the slice as a CSV record, quoting fields as described by
+    /// RFC 4180: fields containing the delimiter, quotes or line breaks are
+    /// wrapped in quotes, and their quotes are doubled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let fields = ["plain", "a, b", "say \"hi\"", ""];
+    ///
+    /// assert_eq!(
+    ///     fields.display().csv().to_string(),
+    ///     r#"plain,"a, b","say ""hi""","#
+    /// );
+    /// ```
+    pub fn csv(self) -> CsvRecord<'a, T, F> {
+        CsvRecord {
+            slice: self.slice,
+            format: self.format,
+            delimiter: ',',
+            line_terminator: "",
+        }
+    }
+
+    /// Displays the slice as a TSV record, see [`csv`](Self::csv).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let fields = ["a", "b\tc"];
+    ///
+    /// assert_eq!(fields.display().tsv().to_string(), "a\t\"b\tc\"");
+    /// ```
+    pub fn tsv(self) -> CsvRecord<'a, T, F> {
+        self.csv().delimiter('\t')
+    }
+
+    /// Displays a slice of slices as a CSV document, with a record for each
+    /// inner slice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let rows = vec![vec!["name", "note"], vec!["Ana", "likes \"CSV\""]];
+    ///
+    /// assert_eq!(
+    ///     rows.display().csv_document().to_string(),
+    ///     "name,note\r\nAna,\"likes \"\"CSV\"\"\"\r\n"
+    /// );
+    /// ```
+    pub fn csv_document<U>(self) -> CsvDocument<'a, T, U>
+    where
+        T: AsRef<[U]>,
+    {
+        CsvDocument {
+            rows: self.slice,
+            delimiter: ',',
+            line_terminator: "\r\n",
+            _fields: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, F> CsvRecord<'a, T, F> {
+    /// Configures the character separating fields.
+    ///
+    /// `,` by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let fields = ["a", "b;c"];
+    ///
+    /// assert_eq!(fields.display().csv().delimiter(';').to_string(), "a;\"b;c\"");
+    /// ```
+    pub fn delimiter(self, delimiter: char) -> Self {
+        Self { delimiter, ..self }
+    }
+
+    /// Configures the line terminator written after the record.
+    ///
+    /// Empty by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let fields = [1, 2];
+    ///
+    /// assert_eq!(
+    ///     fields.display().csv().delimiter(';').line_terminator("\n").to_string(),
+    ///     "1;2\n"
+    /// );
+    /// ```
+    pub fn line_terminator(self, line_terminator: &'a str) -> Self {
+        Self {
+            line_terminator,
+            ..self
+        }
+    }
+}
+
+impl<'a, C, U> CsvDocument<'a, C, U> {
+    /// Configures the character separating fields.
+    ///
+    /// `,` by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let rows = [["a", "b"], ["c", "d|e"]];
+    ///
+    /// assert_eq!(
+    ///     rows.display().csv_document().delimiter('|').to_string(),
+    ///     "a|b\r\nc|\"d|e\"\r\n"
+    /// );
+    /// ```
+    pub fn delimiter(self, delimiter: char) -> Self {
+        Self { delimiter, ..self }
+    }
+
+    /// Configures the line terminator written after each record.
+    ///
+    /// `\r\n` by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let rows = [[1, 2], [3, 4]];
+    ///
+    /// assert_eq!(
+    ///     rows.display().csv_document().line_terminator("\n").to_string(),
+    ///     "1,2\n3,4\n"
+    /// );
+    /// ```
+    pub fn line_terminator(self, line_terminator: &'a str) -> Self {
+        Self {
+            line_terminator,
+            ..self
+        }
+    }
+}
+
+/// Checks whether the text written into it needs to be quoted.
+struct NeedsQuotes {
+    delimiter: char,
+    found: bool,
+}
+
+impl Write for NeedsQuotes {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.found |= s
+            .chars()
+            .any(|c| matches!(c, '"' | '\r' | '\n') || c == self.delimiter);
+        Ok(())
+    }
+}
+
+/// Doubles the quotes written into it.
+struct DoubleQuotes<'w, W>(&'w mut W);
+
+impl<W: Write> Write for DoubleQuotes<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for (index, part) in s.split('"').enumerate() {
+            if index > 0 {
+                self.0.write_str("\"\"")?;
+            }
+            self.0.write_str(part)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes every field of a record, quoted where needed.
+fn fmt_record<T>(
+    f: &mut Formatter<'_>,
+    fields: &[T],
+    delimiter: char,
+    fmt_field: impl Fn(&T, &mut Formatter<'_>) -> core::fmt::Result,
+) -> core::fmt::Result {
+    let spec = Spec::of(f);
+
+    for (index, field) in fields.iter().enumerate() {
+        if index > 0 {
+            f.write_char(delimiter)?;
+        }
+
+        let mut scanner = NeedsQuotes {
+            delimiter,
+            found: false,
+        };
+        spec.write(&mut scanner, |f| fmt_field(field, f))?;
+        if scanner.found {
+            f.write_char('"')?;
+            spec.write(&mut DoubleQuotes(f), |f| fmt_field(field, f))?;
+            f.write_char('"')?;
+        } else {
+            fmt_field(field, f)?;
+        }
+    }
+
+    Ok(())
+}
+
+impl<T, F: ElementFormat<T>> Display for CsvRecord<'_, T, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        fmt_record(f, self.slice, self.delimiter, |field, f| {
+            self.format.fmt_element(field, f)
+        })?;
+
+        f.write_str(self.line_terminator)
+    }
+}
+
+impl<C, U> Display for CsvDocument<'_, C, U>
+where
+    C: AsRef<[U]>,
+    U: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for row in self.rows {
+            fmt_record(f, row.as_ref(), self.delimiter, |field, f| {
+                WithDisplay.fmt_element(field, f)
+            })?;
+            f.write_str(self.line_terminator)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString};
+
+    use crate::SliceDisplay;
+
+    extern crate alloc;
+
+    #[test]
+    fn csv_display() {
+        let fields = ["a;b", "c,d", "line\nbreak", "\"", "cr\r"];
+        assert_eq!(
+            fields.display().csv().to_string(),
+            "a;b,\"c,d\",\"line\nbreak\",\"\"\"\",\"cr\r\""
+        );
+        assert_eq!(
+            fields.display().csv().delimiter(';').to_string(),
+            "\"a;b\";c,d;\"line\nbreak\";\"\"\"\";\"cr\r\""
+        );
+        assert_eq!(format!("{:.1}", [1.25, -3.0].display().csv()), "1.2,-3.0");
+        assert_eq!(
+            [0xab_u8, 0x01].display().hex().csv().to_string(),
+            "0xab,0x1"
+        );
+
+        let rows: [&[&str]; 3] = [&["id", "tags"], &["1", "a\tb"], &[]];
+        assert_eq!(
+            rows.display()
+                .csv_document()
+                .delimiter('\t')
+                .line_terminator("\n")
+                .to_string(),
+            "id\ttags\n1\t\"a\tb\"\n\n"
+        );
+    }
+}
@@ -0,0 +1,555 @@
+use alloc::{vec, vec::Vec};
+use core::fmt::{Display, Formatter};
+
+#[cfg(feature = "ansi")]
+use crate::ansi::Ansi;
+use crate::{
+    style::{write_grouped, Style},
+    ElementFormat, WithDebug, WithDisplay,
+};
+
+/// Displays the differences between two slices, marking removed elements
+/// with `-` and added ones with `+`.
+///
+/// The differences are computed once, as a shortest edit script between both
+/// slices. Slices too far apart are reported as a removal of the old slice
+/// followed by an addition of the new one. Unchanged elements further than
+/// [`context`](SliceDiff::context) from a change are elided.
+///
+/// # Example
+///
+/// ```rust
+/// let old = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+/// let new = [1, 2, 3, 4, 50, 6, 7, 8, 9, 10];
+///
+/// assert_eq!(
+///     slicedisplay::diff(&old, &new).context(1).to_string(),
+///     "[... (3 unchanged) ..., 4, -5, +50, 6, ... (2 unchanged) ..., 9, +10]"
+/// );
+/// ```
+pub fn diff<'a, T: PartialEq>(old: &'a [T], new: &'a [T]) -> SliceDiff<'a, T> {
+    SliceDiff {
+        old,
+        new,
+        edits: edits(old, new),
+        format: WithDisplay,
+        style: Style::default(),
+        context: 3,
+        #[cfg(feature = "ansi")]
+        color: false,
+    }
+}
+
+/// Helper struct for printing the differences between two slices, see
+/// [`diff`].
+#[derive(Clone)]
+pub struct SliceDiff<'a, T, F = WithDisplay> {
+    old: &'a [T],
+    new: &'a [T],
+    edits: Vec<Edit>,
+    format: F,
+    style: Style<'a>,
+    context: usize,
+    #[cfg(feature = "ansi")]
+    color: bool,
+}
+
+/// A step turning the old slice into the new one.
+#[derive(Clone, Copy)]
+enum Edit {
+    /// `len` elements found at `old` and `new` in each slice.
+    Same {
+        old: usize,
+        new: usize,
+        len: usize,
+    },
+    Removed(usize),
+    Added(usize),
+}
+
+/// Above about this many edits between two slices, their differences are reported
+/// as a removal of the whole old slice followed by an addition of the new one,
+/// rather than searching further for a shorter diff.
+const MAX_COST: usize = 1024;
+
+/// Computes the steps turning `old` into `new`, removing elements before
+/// adding the ones replacing them.
+///
+/// This is Myers' algorithm in its linear space variant, finding the middle
+/// of a shortest edit script and recursing on both of its halves.
+fn edits<T: PartialEq>(old: &[T], new: &[T]) -> Vec<Edit> {
+    let mut edits = Vec::new();
+    diff_range(old, new, 0, 0, &mut edits);
+
+    // Within each changed run, removals come before additions.
+    let mut start = 0;
+    while start < edits.len() {
+        let len = edits[start..]
+            .iter()
+            .take_while(|edit| !matches!(edit, Edit::Same { .. }))
+            .count();
+        edits[start..start + len].sort_by_key(|edit| matches!(edit, Edit::Added(_)));
+        start += len.max(1);
+    }
+
+    edits
+}
+
+/// Pushes the steps turning `old` into `new` to `edits`, `old` and `new`
+/// being found at `old_start` and `new_start` in the full slices.
+fn diff_range<T: PartialEq>(
+    old: &[T],
+    new: &[T],
+    old_start: usize,
+    new_start: usize,
+    edits: &mut Vec<Edit>,
+) {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let a = &old[prefix..old.len() - suffix];
+    let b = &new[prefix..new.len() - suffix];
+    let (a_start, b_start) = (old_start + prefix, new_start + prefix);
+
+    push_same(edits, old_start, new_start, prefix);
+    match middle_snake(a, b) {
+        Some((i, j)) => {
+            diff_range(&a[..i], &b[..j], a_start, b_start, edits);
+            diff_range(&a[i..], &b[j..], a_start + i, b_start + j, edits);
+        }
+        None => {
+            edits.extend((a_start..a_start + a.len()).map(Edit::Removed));
+            edits.extend((b_start..b_start + b.len()).map(Edit::Added));
+        }
+    }
+    push_same(edits, a_start + a.len(), b_start + b.len(), suffix);
+}
+
+/// Pushes `len` unchanged elements, merging them with the previous edit if
+/// it is contiguous.
+fn push_same(edits: &mut Vec<Edit>, old: usize, new: usize, len: usize) {
+    match edits.last_mut() {
+        Some(Edit::Same {
+            old: last_old,
+            new: last_new,
+            len: last_len,
+        }) if *last_old + *last_len == old && *last_new + *last_len == new => *last_len += len,
+        _ if len > 0 => edits.push(Edit::Same { old, new, len }),
+        _ => {}
+    }
+}
+
+/// Finds a point `(i, j)` on a shortest edit script turning `a` into `b`,
+/// splitting it into two smaller problems.
+///
+/// Returns `None` when either slice is empty, when no such split exists, or
+/// when the slices are more than about [`MAX_COST`] edits apart.
+fn middle_snake<T: PartialEq>(a: &[T], b: &[T]) -> Option<(usize, usize)> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let delta = n - m;
+    let odd = delta % 2 != 0;
+    let max = ((n + m + 1) / 2).min(MAX_COST as isize / 2);
+    let offset = max + 1;
+
+    // `forward[k + offset]` is the furthest `x` reached on the diagonal
+    // `x - y == k` from the start, and `backward[k + offset]` the same from
+    // the end, in reversed coordinates.
+    let mut forward = vec![0_isize; 2 * offset as usize + 1];
+    let mut backward = vec![0_isize; 2 * offset as usize + 1];
+    let split = |i: isize, j: isize| {
+        let split = (i as usize, j as usize);
+        (split != (0, 0) && split != (a.len(), b.len())).then(|| split)
+    };
+
+    for d in 0..=max {
+        for k in (-d..=d).step_by(2) {
+            let index = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && forward[index - 1] < forward[index + 1]) {
+                forward[index + 1]
+            } else {
+                forward[index - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            forward[index] = x;
+
+            let reverse = delta - k;
+            if odd
+                && (-(d - 1)..=d - 1).contains(&reverse)
+                && x + backward[(reverse + offset) as usize] >= n
+            {
+                return split(x, y);
+            }
+        }
+
+        for k in (-d..=d).step_by(2) {
+            let index = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && backward[index - 1] < backward[index + 1]) {
+                backward[index + 1]
+            } else {
+                backward[index - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[(n - 1 - x) as usize] == b[(m - 1 - y) as usize] {
+                x += 1;
+                y += 1;
+            }
+            backward[index] = x;
+
+            let reverse = delta - k;
+            if !odd && (-d..=d).contains(&reverse) && x + forward[(reverse + offset) as usize] >= n
+            {
+                return split(n - x, m - y);
+            }
+        }
+    }
+
+    None
+}
+
+/// An item of the displayed diff.
+enum Item<'a, T> {
+    Same(usize, &'a T),
+    Removed(usize, &'a T),
+    Added(usize, &'a T),
+    Skipped(usize),
+}
+
+impl<T> Item<'_, T> {
+    /// The marker written ahead of changed elements.
+    fn marker(&self) -> &'static str {
+        match self {
+            Item::Removed(..) => "-",
+            Item::Added(..) => "+",
+            _ => "",
+        }
+    }
+}
+
+/// Walks the edits, eliding unchanged elements away from the changes.
+struct Items<'d, T> {
+    old: &'d [T],
+    new: &'d [T],
+    edits: &'d [Edit],
+    context: usize,
+    edit: usize,
+    offset: usize,
+}
+
+impl<T> Clone for Items<'_, T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'d, T> Iterator for Items<'d, T> {
+    type Item = Item<'d, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let edit = *self.edits.get(self.edit)?;
+        let (item, len) = match edit {
+            Edit::Removed(i) => (Item::Removed(i, &self.old[i]), 1),
+            Edit::Added(j) => (Item::Added(j, &self.new[j]), 1),
+            Edit::Same { old, len, .. } => {
+                let lead = if self.edit > 0 { self.context } else { 0 };
+                let trail = if self.edit + 1 < self.edits.len() {
+                    self.context
+                } else {
+                    0
+                };
+
+                if self.offset == lead && len > lead.saturating_add(trail) {
+                    let skipped = len - lead - trail;
+                    self.offset += skipped - 1;
+                    (Item::Skipped(skipped), len)
+                } else {
+                    let i = old + self.offset;
+                    (Item::Same(i, &self.old[i]), len)
+                }
+            }
+        };
+
+        self.offset += 1;
+        if self.offset == len {
+            self.edit += 1;
+            self.offset = 0;
+        }
+
+        Some(item)
+    }
+}
+
+impl<'a, T, F> SliceDiff<'a, T, F> {
+    style_builders!(
+        terminator,
+        terminator_str,
+        no_terminators,
+        delimiter,
+        delimiter_str,
+        should_space,
+        ellipsis,
+        show_omitted,
+        pretty,
+        indent,
+        trailing_delimiter,
+        wrap,
+        with_indices,
+        index_format,
+        index_offset,
+        paint_terminators,
+        paint_delimiters,
+        paint_indices,
+        paint_elements,
+        no_color
+    );
+
+    /// Configures how many unchanged elements are kept around each change.
+    ///
+    /// 3 by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let old = [1, 2, 3, 4, 5];
+    /// let new = [1, 2, 3, 4, 6];
+    ///
+    /// assert_eq!(slicedisplay::diff(&old, &new).context(0).to_string(), "[... (4 unchanged) ..., -5, +6]");
+    /// assert_eq!(
+    ///     slicedisplay::diff(&old, &new).context(usize::MAX).to_string(),
+    ///     "[1, 2, 3, 4, -5, +6]"
+    /// );
+    /// ```
+    pub fn context(mut self, context: usize) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Sets whether removed and added elements should be colored in red and
+    /// green, using ANSI escape codes.
+    ///
+    /// False by default. Like the other styles, colors are left out by
+    /// [`no_color`](Self::no_color) and the alternate flag (`{:#}`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let old = ["a"];
+    /// let new = ["b"];
+    ///
+    /// assert_eq!(
+    ///     slicedisplay::diff(&old, &new).color(true).to_string(),
+    ///     "[\x1b[31m-a\x1b[0m, \x1b[32m+b\x1b[0m]"
+    /// );
+    /// assert_eq!(
+    ///     slicedisplay::diff(&old, &new).color(true).no_color().to_string(),
+    ///     "[-a, +b]"
+    /// );
+    /// ```
+    #[cfg(feature = "ansi")]
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Displays the elements using their [`Debug`](core::fmt::Debug)
+    /// implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let old = ["a", "b"];
+    /// let new = ["a", "c"];
+    ///
+    /// assert_eq!(slicedisplay::diff(&old, &new).debug().to_string(), r#"["a", -"b", +"c"]"#);
+    /// ```
+    pub fn debug(self) -> SliceDiff<'a, T, WithDebug> {
+        SliceDiff {
+            old: self.old,
+            new: self.new,
+            edits: self.edits,
+            format: WithDebug,
+            style: self.style,
+            context: self.context,
+            #[cfg(feature = "ansi")]
+            color: self.color,
+        }
+    }
+}
+
+impl<T, F: ElementFormat<T>> Display for SliceDiff<'_, T, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let items = Items {
+            old: self.old,
+            new: self.new,
+            edits: &self.edits,
+            context: self.context,
+            edit: 0,
+            offset: 0,
+        };
+
+        // Indices refer to either slice depending on the item. The marker
+        // goes ahead of them when they are written.
+        let indices = self.style.indices.is_some();
+        self.style.fmt_iter_weighted(
+            f,
+            items,
+            |_| 1,
+            |item, _, f| match *item {
+                Item::Same(index, _) => self.style.fmt_index(f, index),
+                Item::Removed(index, _) | Item::Added(index, _) if indices => {
+                    self.paint_change(f, item, |f| f.write_str(item.marker()))?;
+                    self.style.fmt_index(f, index)
+                }
+                _ => Ok(()),
+            },
+            |item, f| match *item {
+                Item::Same(_, elem) => self.format.fmt_element(elem, f),
+                Item::Removed(_, elem) | Item::Added(_, elem) => self.paint_change(f, item, |f| {
+                    if !indices {
+                        f.write_str(item.marker())?;
+                    }
+                    self.format.fmt_element(elem, f)
+                }),
+                Item::Skipped(skipped) => {
+                    let ellipsis = self.style.ellipsis;
+                    f.write_str(ellipsis)?;
+                    if self.style.show_omitted {
+                        f.write_str(" (")?;
+                        write_grouped(f, skipped)?;
+                        write!(f, " unchanged) {ellipsis}")?;
+                    }
+
+                    Ok(())
+                }
+            },
+        )
+    }
+}
+
+impl<T, F> SliceDiff<'_, T, F> {
+    /// Writes with `write`, in the color of `item` if colors are enabled.
+    #[cfg(feature = "ansi")]
+    fn paint_change(
+        &self,
+        f: &mut Formatter<'_>,
+        item: &Item<'_, T>,
+        write: impl FnOnce(&mut Formatter<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        let ansi = match item {
+            Item::Removed(..) if self.color => Some(Ansi::RED),
+            Item::Added(..) if self.color => Some(Ansi::GREEN),
+            _ => None,
+        };
+        self.style.paint_with(f, ansi, write)
+    }
+
+    #[cfg(not(feature = "ansi"))]
+    fn paint_change(
+        &self,
+        f: &mut Formatter<'_>,
+        _item: &Item<'_, T>,
+        write: impl FnOnce(&mut Formatter<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        write(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString, vec::Vec};
+
+    use crate::diff;
+
+    extern crate alloc;
+
+    #[test]
+    fn diff_display() {
+        let old: Vec<u32> = (0..1000).collect();
+        let mut new = old.clone();
+        new[500] = 0;
+        new.remove(10);
+        new.push(1000);
+        assert_eq!(
+            diff(&old, &new).context(1).to_string(),
+            "[... (9 unchanged) ..., 9, -10, 11, ... (487 unchanged) ..., 499, -500, +0, 501, \
+             ... (497 unchanged) ..., 999, +1000]"
+        );
+        assert_eq!(
+            diff(&old[..3], &[]).with_indices().to_string(),
+            "[-0: 0, -1: 1, -2: 2]"
+        );
+        assert_eq!(diff(&[1, 2, 3], &[3, 1, 2]).to_string(), "[+3, 1, 2, -3]");
+        assert_eq!(
+            diff(&[1, 1, 1, 1], &[1, 1, 1, 1])
+                .context(0)
+                .show_omitted(false)
+                .to_string(),
+            "[...]"
+        );
+        assert_eq!(
+            format!("{:#}", diff(&['a', 'b'], &['a', 'c']).context(0)),
+            "[\n    ... (1 unchanged) ...,\n    -b,\n    +c,\n]"
+        );
+
+        let empty: [u8; 0] = [];
+        assert_eq!(diff(&empty, &empty).to_string(), "[]");
+    }
+
+    #[test]
+    fn diff_large() {
+        let old: Vec<u32> = (0..20_000).collect();
+        let mut new = old.clone();
+        new[0] = 20_000;
+        new[19_999] = 20_001;
+        assert_eq!(
+            diff(&old, &new).context(1).to_string(),
+            "[-0, +20000, 1, ... (19_996 unchanged) ..., 19998, -19999, +20001]"
+        );
+
+        let new: Vec<u32> = (20_000..40_000).collect();
+        let shown = diff(&old, &new).to_string();
+        assert!(shown.starts_with("[-0, -1, ") && shown.ends_with(", +39998, +39999]"));
+        assert_eq!(shown.matches('-').count(), 20_000);
+        assert_eq!(shown.matches('+').count(), 20_000);
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn diff_color() {
+        use crate::Ansi;
+
+        let old = [1, 2, 3];
+        let new = [1, 20, 3];
+        assert_eq!(
+            diff(&old, &new).color(true).wrap(11).to_string(),
+            "[1, \x1b[31m-2\x1b[0m,\n    \x1b[32m+20\x1b[0m, 3]"
+        );
+        assert_eq!(
+            format!("{:#}", diff(&old, &new).color(true).context(0)),
+            "[\n    ... (1 unchanged) ...,\n    -2,\n    +20,\n    ... (1 unchanged) ...,\n]"
+        );
+        assert_eq!(
+            diff(&old, &new)
+                .color(true)
+                .context(0)
+                .with_indices()
+                .paint_indices(Ansi::CYAN)
+                .paint_elements(Ansi::BOLD)
+                .show_omitted(false)
+                .to_string(),
+            "[\x1b[1m...\x1b[0m, \x1b[31m-\x1b[0m\x1b[36m1: \x1b[0m\x1b[1m\x1b[31m2\x1b[0m\x1b[0m, \
+             \x1b[32m+\x1b[0m\x1b[36m1: \x1b[0m\x1b[1m\x1b[32m20\x1b[0m\x1b[0m, \x1b[1m...\x1b[0m]"
+        );
+    }
+}
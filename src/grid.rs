@@ -0,0 +1,293 @@
+use core::fmt::{Display, Formatter, Write};
+
+use crate::{
+    spec::{Counter, Spec},
+    ElementFormat, SliceDisplayImpl,
+};
+
+/// Displays a flat, row-major slice as a matrix, see
+/// [`SliceDisplayImpl::grid`].
+#[derive(Clone, Copy)]
+pub struct GridDisplay<'a, T, F> {
+    slice: &'a [T],
+    format: F,
+    columns: usize,
+    indices: bool,
+    max_rows: Option<usize>,
+    max_columns: Option<usize>,
+}
+
+impl<'a, T, F> SliceDisplayImpl<'a, T, F> {
+    /// Displays the slice as a row-major matrix of `columns` columns, one
+    /// row per line. Values are right-aligned to the widest one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `columns` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let matrix = [1.0, 2.5, -3.0, 10.0, 0.0, 4.0];
+    ///
+    /// assert_eq!(
+    ///     matrix.display().grid(3).to_string(),
+    ///     "  1 2.5  -3\n 10   0   4"
+    /// );
+    /// assert_eq!(
+    ///     format!("{:.1}", matrix.display().grid(2)),
+    ///     " 1.0  2.5\n-3.0 10.0\n 0.0  4.0"
+    /// );
+    /// ```
+    pub fn grid(self, columns: usize) -> GridDisplay<'a, T, F> {
+        assert!(columns > 0, "a grid needs at least one column");
+        GridDisplay {
+            slice: self.slice,
+            format: self.format,
+            columns,
+            indices: false,
+            max_rows: None,
+            max_columns: None,
+        }
+    }
+}
+
+impl<'a, T, F> GridDisplay<'a, T, F> {
+    /// Sets whether row and column indices should be displayed.
+    ///
+    /// False by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let matrix = [1, 2, 3, 4];
+    ///
+    /// assert_eq!(
+    ///     matrix.display().grid(2).indices(true).to_string(),
+    ///     "  0 1\n0 1 2\n1 3 4"
+    /// );
+    /// ```
+    pub fn indices(self, indices: bool) -> Self {
+        Self { indices, ..self }
+    }
+
+    /// Only displays the first and last rows when there are more than
+    /// `max_rows` of them, eliding the others with `⋮`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let matrix: Vec<u32> = (0..100).collect();
+    ///
+    /// assert_eq!(
+    ///     matrix.display().grid(10).max_rows(2).max_columns(4).to_string(),
+    ///     " 0  1 …  8  9\n ⋮  ⋮ ⋱  ⋮  ⋮\n90 91 … 98 99"
+    /// );
+    /// ```
+    pub fn max_rows(self, max_rows: usize) -> Self {
+        Self {
+            max_rows: Some(max_rows),
+            ..self
+        }
+    }
+
+    /// Only displays the first and last columns when there are more than
+    /// `max_columns` of them, eliding the others with `…`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let row = [1, 2, 3, 4, 5];
+    ///
+    /// assert_eq!(row.display().grid(5).max_columns(2).to_string(), "1 … 5");
+    /// ```
+    pub fn max_columns(self, max_columns: usize) -> Self {
+        Self {
+            max_columns: Some(max_columns),
+            ..self
+        }
+    }
+
+    fn rows(&self) -> usize {
+        (self.slice.len() + self.columns - 1) / self.columns
+    }
+}
+
+/// The positions shown along one dimension of the grid.
+#[derive(Clone, Copy)]
+struct Axis {
+    len: usize,
+    head: usize,
+    tail: usize,
+}
+
+/// A position along an [`Axis`].
+#[derive(Clone, Copy)]
+enum Position {
+    Index(usize),
+    Elided,
+}
+
+impl Axis {
+    fn new(len: usize, max: Option<usize>) -> Self {
+        match max {
+            Some(max) if max < len => Self {
+                len,
+                head: (max + 1) / 2,
+                tail: max / 2,
+            },
+            _ => Self {
+                len,
+                head: len,
+                tail: 0,
+            },
+        }
+    }
+
+    fn positions(self) -> impl Iterator<Item = Position> + Clone {
+        let elided = self.head + self.tail < self.len;
+
+        (0..self.head)
+            .map(Position::Index)
+            .chain(elided.then(|| Position::Elided))
+            .chain(
+                (self.len - self.tail..self.len)
+                    .filter(move |_| elided)
+                    .map(Position::Index),
+            )
+    }
+}
+
+impl<T, F: ElementFormat<T>> GridDisplay<'_, T, F> {
+    fn width_of(
+        &self,
+        spec: &Spec,
+        fmt: impl Fn(&mut Formatter<'_>) -> core::fmt::Result,
+    ) -> Result<usize, core::fmt::Error> {
+        let mut counter = Counter(0);
+        spec.write(&mut counter, fmt)?;
+        Ok(counter.0)
+    }
+}
+
+impl<T, F: ElementFormat<T>> Display for GridDisplay<'_, T, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let spec = Spec::of(f);
+        let rows = Axis::new(self.rows(), self.max_rows);
+        let columns = Axis::new(self.columns, self.max_columns);
+        let cell = |row: usize, column: usize| self.slice.get(row * self.columns + column);
+
+        let mut width = 1;
+        for row in rows.positions() {
+            for column in columns.positions() {
+                if let (Position::Index(row), Position::Index(column)) = (row, column) {
+                    if let Some(elem) = cell(row, column) {
+                        let elem_width =
+                            self.width_of(&spec, |f| self.format.fmt_element(elem, f))?;
+                        width = width.max(elem_width);
+                    }
+                }
+            }
+        }
+        let label_width = width_of_index(rows.len.saturating_sub(1));
+
+        if self.indices && rows.len > 0 {
+            width = width.max(width_of_index(self.columns - 1));
+            write!(f, "{:label_width$}", "")?;
+            for column in columns.positions() {
+                match column {
+                    Position::Index(column) => write!(f, " {column:>width$}")?,
+                    Position::Elided => f.write_str(" …")?,
+                }
+            }
+            f.write_char('\n')?;
+        }
+
+        for (line, row) in rows.positions().enumerate() {
+            if line > 0 {
+                f.write_char('\n')?;
+            }
+            match row {
+                Position::Index(row) if self.indices => write!(f, "{row:>label_width$} ")?,
+                Position::Elided if self.indices => write!(f, "{:label_width$} ", "")?,
+                _ => {}
+            }
+
+            for (index, column) in columns.positions().enumerate() {
+                // The last row stops after its last cell, leaving no trailing
+                // whitespace.
+                if let (Position::Index(row), Position::Index(column)) = (row, column) {
+                    if cell(row, column).is_none() {
+                        break;
+                    }
+                }
+                if index > 0 {
+                    f.write_char(' ')?;
+                }
+                match (row, column) {
+                    (Position::Index(row), Position::Index(column)) => {
+                        if let Some(elem) = cell(row, column) {
+                            let elem_width =
+                                self.width_of(&spec, |f| self.format.fmt_element(elem, f))?;
+                            write!(f, "{:1$}", "", width - elem_width)?;
+                            self.format.fmt_element(elem, f)?
+                        }
+                    }
+                    (Position::Index(_), Position::Elided) => f.write_char('…')?,
+                    (Position::Elided, Position::Index(_)) => write!(f, "{:>width$}", "⋮")?,
+                    (Position::Elided, Position::Elided) => f.write_char('⋱')?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Amount of digits of `index`.
+fn width_of_index(index: usize) -> usize {
+    let mut counter = Counter(0);
+    let _ = write!(counter, "{index}");
+    counter.0
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString, vec::Vec};
+
+    use crate::SliceDisplay;
+
+    extern crate alloc;
+
+    #[test]
+    fn grid_display() {
+        let numbers = [1, 20, 300, 4, 5];
+        assert_eq!(
+            numbers.display().grid(2).to_string(),
+            "  1  20\n300   4\n  5"
+        );
+        assert_eq!(
+            numbers.display().hex().grid(3).indices(true).to_string(),
+            "      0     1     2\n0   0x1  0x14 0x12c\n1   0x4   0x5"
+        );
+
+        let matrix: Vec<f32> = (0..12).map(|n| n as f32 / 4.0).collect();
+        assert_eq!(
+            format!("{:.2}", matrix.display().grid(4).max_rows(2).indices(true)),
+            "     0    1    2    3\n0 0.00 0.25 0.50 0.75\n     ⋮    ⋮    ⋮    ⋮\n2 2.00 2.25 2.50 2.75"
+        );
+        assert_eq!(matrix.display().grid(12).max_columns(0).to_string(), "…");
+        assert_eq!([1, 2, 3].display().grid(2).to_string(), "1 2\n3");
+
+        let empty: [u8; 0] = [];
+        assert_eq!(empty.display().grid(3).indices(true).to_string(), "");
+    }
+}
@@ -0,0 +1,226 @@
+use core::fmt::{Display, Formatter, Write};
+
+/// Displays bytes like `xxd`: offset, hexadecimal columns and an ASCII gutter.
+///
+/// Lines are separated by `\n`, without a trailing one.
+///
+/// # Example
+///
+/// ```rust
+/// use slicedisplay::SliceDisplay;
+///
+/// let bytes = b"Hello, world!\n";
+///
+/// assert_eq!(
+///     bytes.hexdump().to_string(),
+///     "00000000: 4865 6c6c 6f2c 2077 6f72 6c64 210a       Hello, world!."
+/// );
+/// ```
+#[derive(Clone, Copy)]
+pub struct HexDump<'a> {
+    bytes: &'a [u8],
+    bytes_per_line: usize,
+    group: usize,
+    uppercase: bool,
+    start_offset: usize,
+}
+
+impl<'a> HexDump<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            bytes_per_line: 16,
+            group: 2,
+            uppercase: false,
+            start_offset: 0,
+        }
+    }
+
+    /// Configures how many bytes are displayed on each line.
+    ///
+    /// 16 by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes_per_line` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let bytes = b"abcdef";
+    ///
+    /// assert_eq!(
+    ///     bytes.hexdump().bytes_per_line(4).to_string(),
+    ///     "00000000: 6162 6364  abcd\n00000004: 6566       ef"
+    /// );
+    /// ```
+    pub fn bytes_per_line(self, bytes_per_line: usize) -> Self {
+        assert!(
+            bytes_per_line > 0,
+            "bytes per line must be greater than zero"
+        );
+        Self {
+            bytes_per_line,
+            ..self
+        }
+    }
+
+    /// Configures how many bytes are grouped together between spaces.
+    ///
+    /// 2 by default, 0 disables grouping.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let bytes = b"abcd";
+    ///
+    /// assert_eq!(
+    ///     bytes.hexdump().bytes_per_line(4).group(1).to_string(),
+    ///     "00000000: 61 62 63 64  abcd"
+    /// );
+    /// assert_eq!(
+    ///     bytes.hexdump().bytes_per_line(4).group(0).to_string(),
+    ///     "00000000: 61626364  abcd"
+    /// );
+    /// ```
+    pub fn group(self, group: usize) -> Self {
+        Self { group, ..self }
+    }
+
+    /// Sets whether hexadecimal digits should be uppercase.
+    ///
+    /// False by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let bytes = [0xde, 0xad];
+    ///
+    /// assert_eq!(
+    ///     bytes.hexdump().bytes_per_line(2).uppercase(true).to_string(),
+    ///     "00000000: DEAD  .."
+    /// );
+    /// ```
+    pub fn uppercase(self, uppercase: bool) -> Self {
+        Self { uppercase, ..self }
+    }
+
+    /// Configures the offset displayed for the first byte.
+    ///
+    /// 0 by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let bytes = b"ab";
+    ///
+    /// assert_eq!(
+    ///     bytes.hexdump().start_offset(0x1000).bytes_per_line(2).to_string(),
+    ///     "00001000: 6162  ab"
+    /// );
+    /// ```
+    pub fn start_offset(self, start_offset: usize) -> Self {
+        Self {
+            start_offset,
+            ..self
+        }
+    }
+
+    fn fmt_line(&self, f: &mut Formatter<'_>, offset: usize, line: &[u8]) -> core::fmt::Result {
+        write!(f, "{offset:08x}:")?;
+        for column in 0..self.bytes_per_line {
+            if column == 0 || (self.group > 0 && column % self.group == 0) {
+                f.write_char(' ')?;
+            }
+            match line.get(column) {
+                Some(byte) if self.uppercase => write!(f, "{byte:02X}")?,
+                Some(byte) => write!(f, "{byte:02x}")?,
+                None => f.write_str("  ")?,
+            }
+        }
+
+        f.write_str("  ")?;
+        for &byte in line {
+            let ascii = if byte.is_ascii_graphic() || byte == b' ' {
+                byte as char
+            } else {
+                '.'
+            };
+            f.write_char(ascii)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for HexDump<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for (index, line) in self.bytes.chunks(self.bytes_per_line).enumerate() {
+            if index > 0 {
+                f.write_char('\n')?;
+            }
+            let offset = self
+                .start_offset
+                .wrapping_add(index.wrapping_mul(self.bytes_per_line));
+            self.fmt_line(f, offset, line)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString};
+
+    use crate::SliceDisplay;
+
+    extern crate alloc;
+
+    #[test]
+    fn hexdump() {
+        let empty: [u8; 0] = [];
+        assert_eq!(empty.hexdump().to_string(), "");
+
+        let bytes: alloc::vec::Vec<u8> = (0x3e..0x52).collect();
+        assert_eq!(
+            bytes.hexdump().to_string(),
+            "00000000: 3e3f 4041 4243 4445 4647 4849 4a4b 4c4d  >?@ABCDEFGHIJKLM\n\
+             00000010: 4e4f 5051                                NOPQ"
+        );
+        assert_eq!(
+            bytes[..6]
+                .as_ref()
+                .hexdump()
+                .bytes_per_line(3)
+                .group(3)
+                .uppercase(true)
+                .start_offset(0xff)
+                .to_string(),
+            "000000ff: 3E3F40  >?@\n00000102: 414243  ABC"
+        );
+        assert_eq!(
+            [0x00, 0x7f, b' ', 0xff].hexdump().group(0).to_string(),
+            "00000000: 007f20ff                          .. ."
+        );
+        assert_eq!(
+            [1_u8, 2, 3]
+                .hexdump()
+                .bytes_per_line(1)
+                .start_offset(usize::MAX)
+                .to_string(),
+            format!(
+                "{:08x}: 01  .\n00000000: 02  .\n00000001: 03  .",
+                usize::MAX
+            )
+        );
+    }
+}
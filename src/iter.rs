@@ -0,0 +1,167 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{style::Style, ElementFormat, WithDebug, WithDisplay};
+
+/// Displays the items of an iterator, without collecting them first.
+///
+/// The iterator is cloned every time the display is formatted, so it must
+/// be cheap to clone, as iterators over borrowed collections are.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::BTreeSet;
+///
+/// use slicedisplay::iter_display;
+///
+/// let set = BTreeSet::from([3, 1, 2]);
+///
+/// assert_eq!(iter_display(&set).to_string(), "[1, 2, 3]");
+/// assert_eq!(
+///     iter_display(set.iter().filter(|n| *n % 2 == 1))
+///         .terminator('{', '}')
+///         .to_string(),
+///     "{1, 3}"
+/// );
+/// ```
+pub fn iter_display<'a, I>(iter: I) -> IterDisplay<'a, I::IntoIter>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    IterDisplay {
+        iter: iter.into_iter(),
+        format: WithDisplay,
+        style: Style::default(),
+    }
+}
+
+/// Helper struct for printing iterators, see [`iter_display`].
+#[derive(Clone, Copy)]
+pub struct IterDisplay<'a, I, F = WithDisplay> {
+    iter: I,
+    format: F,
+    style: Style<'a>,
+}
+
+impl<'a, I, F> IterDisplay<'a, I, F> {
+    style_builders!(
+        terminator,
+        terminator_str,
+        no_terminators,
+        delimiter,
+        delimiter_str,
+        should_space,
+        truncate,
+        ellipsis,
+        show_omitted,
+        conjunction,
+        oxford_comma,
+        group,
+        pretty,
+        indent,
+        trailing_delimiter,
+        wrap,
+        with_indices,
+        index_format,
+        index_offset,
+        paint_terminators,
+        paint_delimiters,
+        paint_indices,
+        paint_elements,
+        no_color
+    );
+
+    /// Displays the items using their [`Debug`](core::fmt::Debug)
+    /// implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::iter_display;
+    ///
+    /// let words = ["a", "b"];
+    ///
+    /// assert_eq!(
+    ///     iter_display(words.iter().map(|word| Some(*word)))
+    ///         .debug()
+    ///         .to_string(),
+    ///     r#"[Some("a"), Some("b")]"#
+    /// );
+    /// ```
+    pub fn debug(self) -> IterDisplay<'a, I, WithDebug> {
+        IterDisplay {
+            iter: self.iter,
+            format: WithDebug,
+            style: self.style,
+        }
+    }
+
+    /// Writes each item through `format`, see
+    /// [`SliceDisplayImpl::map_display`](crate::SliceDisplayImpl::map_display).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::fmt::Write;
+    ///
+    /// use slicedisplay::iter_display;
+    ///
+    /// let words = ["a", "b"];
+    ///
+    /// assert_eq!(
+    ///     iter_display(&words).map_display(|word, f| write!(f, "{word:?}")).to_string(),
+    ///     r#"["a", "b"]"#
+    /// );
+    /// ```
+    pub fn map_display<G>(self, format: G) -> IterDisplay<'a, I, G>
+    where
+        I: Iterator,
+        G: Fn(&I::Item, &mut Formatter<'_>) -> core::fmt::Result,
+    {
+        IterDisplay {
+            iter: self.iter,
+            format,
+            style: self.style,
+        }
+    }
+}
+
+impl<I, F> Display for IterDisplay<'_, I, F>
+where
+    I: Iterator + Clone,
+    F: ElementFormat<I::Item>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.style.fmt_iter(f, self.iter.clone(), |elem, f| {
+            self.format.fmt_element(elem, f)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::VecDeque, format, string::ToString};
+
+    use crate::iter_display;
+
+    extern crate alloc;
+
+    #[test]
+    fn iter_display_collections() {
+        let deque = VecDeque::from([1, 2, 3, 4, 5]);
+        assert_eq!(iter_display(&deque).to_string(), "[1, 2, 3, 4, 5]");
+        assert_eq!(
+            iter_display(deque.iter().map(|n| n * 10))
+                .truncate(1, 1)
+                .delimiter(';')
+                .to_string(),
+            "[10; ... (3 more) ...; 50]"
+        );
+        assert_eq!(
+            format!("{:>3}", iter_display(deque.iter().rev()).no_terminators()),
+            "  5,   4,   3,   2,   1"
+        );
+        assert_eq!(iter_display(deque.iter().skip(5)).to_string(), "[]");
+    }
+}
@@ -0,0 +1,366 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::{Display, Formatter, Write};
+
+use crate::{
+    style::{Style, Token},
+    SliceDisplayImpl,
+};
+
+/// How non-finite floats, which JSON cannot represent, are written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NonFinite {
+    /// Written as `null`.
+    Null,
+    /// Written as the strings `"NaN"`, `"inf"` and `"-inf"`.
+    String,
+    /// Makes formatting fail.
+    Error,
+}
+
+/// The options used to write JSON values, see [`SliceDisplayImpl::json`].
+#[derive(Clone, Copy, Debug)]
+pub struct Json {
+    non_finite: NonFinite,
+    ascii_only: bool,
+    should_space: bool,
+}
+
+/// Displays a slice as a JSON array, see [`SliceDisplayImpl::json`].
+///
+/// Only the options that keep the output valid JSON can be configured.
+#[derive(Clone, Copy)]
+pub struct JsonDisplay<'a, T> {
+    slice: &'a [T],
+    json: Json,
+    style: Style<'a>,
+}
+
+impl Json {
+    /// The layout of JSON arrays.
+    fn style() -> Style<'static> {
+        Style {
+            terminators: (Token::Char('['), Token::Char(']')),
+            delimiter: Token::Char(','),
+            trailing_delimiter: false,
+            ..Style::default()
+        }
+    }
+}
+
+/// A value that can be written as JSON.
+pub trait JsonValue {
+    /// Writes the value as JSON, following the options of `json`.
+    fn fmt_json(&self, f: &mut Formatter<'_>, json: &Json) -> core::fmt::Result;
+}
+
+impl<'a, T, F> SliceDisplayImpl<'a, T, F> {
+    /// Displays the slice as a JSON array.
+    ///
+    /// Strings are quoted and escaped, numbers and booleans are written as is
+    /// and `None` is written as `null`. Options that would not produce valid
+    /// JSON, such as truncation or indices, are dropped, while the spacing and
+    /// the multi-line layout are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let words = ["plain", "with \"quotes\"", "tab\there"];
+    /// let numbers = [Some(1.5), None, Some(f64::NAN)];
+    ///
+    /// assert_eq!(
+    ///     words.display().json().to_string(),
+    ///     r#"["plain", "with \"quotes\"", "tab\there"]"#
+    /// );
+    /// assert_eq!(numbers.display().json().to_string(), "[1.5, null, null]");
+    /// ```
+    pub fn json(self) -> JsonDisplay<'a, T> {
+        JsonDisplay {
+            slice: self.slice,
+            json: Json {
+                non_finite: NonFinite::Null,
+                ascii_only: false,
+                should_space: true,
+            },
+            style: Style {
+                should_space: self.style.should_space,
+                pretty: self.style.pretty,
+                indent: self.style.indent,
+                ..Json::style()
+            },
+        }
+    }
+}
+
+impl<'a, T> JsonDisplay<'a, T> {
+    style_builders!(should_space, pretty, indent);
+
+    /// Configures how NaN and infinite floats are written.
+    ///
+    /// [`NonFinite::Null`] by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::{NonFinite, SliceDisplay};
+    ///
+    /// let numbers = [1.0, f64::INFINITY];
+    ///
+    /// assert_eq!(
+    ///     numbers.display().json().non_finite(NonFinite::String).to_string(),
+    ///     r#"[1, "inf"]"#
+    /// );
+    /// assert!(std::fmt::write(
+    ///     &mut String::new(),
+    ///     format_args!("{}", numbers.display().json().non_finite(NonFinite::Error))
+    /// )
+    /// .is_err());
+    /// ```
+    pub fn non_finite(mut self, non_finite: NonFinite) -> Self {
+        self.json.non_finite = non_finite;
+        self
+    }
+
+    /// Sets whether characters outside of ASCII should be escaped as
+    /// `\uXXXX` sequences.
+    ///
+    /// False by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let words = ["café", "🦀"];
+    ///
+    /// assert_eq!(
+    ///     words.display().json().ascii_only(true).to_string(),
+    ///     r#"["caf\u00e9", "\ud83e\udd80"]"#
+    /// );
+    /// ```
+    pub fn ascii_only(mut self, ascii_only: bool) -> Self {
+        self.json.ascii_only = ascii_only;
+        self
+    }
+}
+
+impl<T: JsonValue> Display for JsonDisplay<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        // Nested arrays are spaced like the outer one.
+        let json = Json {
+            should_space: self.style.should_space,
+            ..self.json
+        };
+        self.style
+            .fmt_iter(f, self.slice.iter(), |value, f| value.fmt_json(f, &json))
+    }
+}
+
+/// Writes `s` as a quoted JSON string.
+fn fmt_json_str(f: &mut Formatter<'_>, s: &str, json: &Json) -> core::fmt::Result {
+    f.write_char('"')?;
+
+    let mut start = 0;
+    for (index, c) in s.char_indices() {
+        let escaped = match c {
+            '"' | '\\' => true,
+            '\u{0}'..='\u{1f}' => true,
+            _ => json.ascii_only && !c.is_ascii(),
+        };
+        if !escaped {
+            continue;
+        }
+
+        f.write_str(&s[start..index])?;
+        start = index + c.len_utf8();
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            '\u{8}' => f.write_str("\\b")?,
+            '\u{c}' => f.write_str("\\f")?,
+            _ => {
+                let mut units = [0; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    write!(f, "\\u{unit:04x}")?;
+                }
+            }
+        }
+    }
+    f.write_str(&s[start..])?;
+
+    f.write_char('"')
+}
+
+impl JsonValue for str {
+    fn fmt_json(&self, f: &mut Formatter<'_>, json: &Json) -> core::fmt::Result {
+        fmt_json_str(f, self, json)
+    }
+}
+
+impl JsonValue for String {
+    fn fmt_json(&self, f: &mut Formatter<'_>, json: &Json) -> core::fmt::Result {
+        fmt_json_str(f, self, json)
+    }
+}
+
+impl JsonValue for char {
+    fn fmt_json(&self, f: &mut Formatter<'_>, json: &Json) -> core::fmt::Result {
+        fmt_json_str(f, self.encode_utf8(&mut [0; 4]), json)
+    }
+}
+
+impl JsonValue for bool {
+    fn fmt_json(&self, f: &mut Formatter<'_>, _: &Json) -> core::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+macro_rules! impl_json_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl JsonValue for $ty {
+                fn fmt_json(&self, f: &mut Formatter<'_>, _: &Json) -> core::fmt::Result {
+                    // Formatter options such as `+` or padding would not
+                    // produce valid JSON, so they are not forwarded.
+                    write!(f, "{self}")
+                }
+            }
+        )*
+    };
+}
+
+impl_json_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_json_float {
+    ($($ty:ty),*) => {
+        $(
+            impl JsonValue for $ty {
+                fn fmt_json(&self, f: &mut Formatter<'_>, json: &Json) -> core::fmt::Result {
+                    if self.is_finite() {
+                        return write!(f, "{self}");
+                    }
+
+                    match json.non_finite {
+                        NonFinite::Null => f.write_str("null"),
+                        NonFinite::String => write!(f, "\"{self}\""),
+                        NonFinite::Error => Err(core::fmt::Error),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_json_float!(f32, f64);
+
+impl<T: JsonValue> JsonValue for Option<T> {
+    fn fmt_json(&self, f: &mut Formatter<'_>, json: &Json) -> core::fmt::Result {
+        match self {
+            Some(value) => value.fmt_json(f, json),
+            None => f.write_str("null"),
+        }
+    }
+}
+
+impl<T: JsonValue + ?Sized> JsonValue for &T {
+    fn fmt_json(&self, f: &mut Formatter<'_>, json: &Json) -> core::fmt::Result {
+        (**self).fmt_json(f, json)
+    }
+}
+
+impl<T: JsonValue> JsonValue for [T] {
+    fn fmt_json(&self, f: &mut Formatter<'_>, json: &Json) -> core::fmt::Result {
+        let style = Style {
+            should_space: json.should_space,
+            ..Json::style()
+        };
+        style.fmt_iter(f, self.iter(), |value, f| value.fmt_json(f, json))
+    }
+}
+
+impl<T: JsonValue, const N: usize> JsonValue for [T; N] {
+    fn fmt_json(&self, f: &mut Formatter<'_>, json: &Json) -> core::fmt::Result {
+        self.as_slice().fmt_json(f, json)
+    }
+}
+
+impl<T: JsonValue> JsonValue for Vec<T> {
+    fn fmt_json(&self, f: &mut Formatter<'_>, json: &Json) -> core::fmt::Result {
+        self.as_slice().fmt_json(f, json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString, vec};
+
+    use crate::{NonFinite, SliceDisplay};
+
+    extern crate alloc;
+
+    #[test]
+    fn json_display() {
+        let empty: [u8; 0] = [];
+        assert_eq!(empty.display().json().to_string(), "[]");
+
+        let strings = ["\u{1}\u{8}\u{c}\r\n", "\\", "ünï"];
+        assert_eq!(
+            strings.display().json().to_string(),
+            r#"["\u0001\b\f\r\n", "\\", "ünï"]"#
+        );
+        assert_eq!(['"', 'é'].display().json().to_string(), r#"["\"", "é"]"#);
+
+        let nested = vec![vec![Some(-1_i64)], vec![None, Some(2)]];
+        assert_eq!(
+            nested.display().json().should_space(false).to_string(),
+            "[[-1],[null,2]]"
+        );
+        assert_eq!(
+            format!("{:#}", nested.display().json()),
+            "[\n    [\n        -1\n    ],\n    [\n        null,\n        2\n    ]\n]"
+        );
+
+        let numbers = [1, 2, 3, 4, 5];
+        assert_eq!(format!("{:+}", numbers.display().json()), "[1, 2, 3, 4, 5]");
+        assert_eq!(format!("{:x>4.1}", [0.25].display().json()), "[0.25]");
+        assert_eq!(
+            numbers
+                .display()
+                .truncate(1, 1)
+                .with_indices()
+                .group(2)
+                .wrap(4)
+                .json()
+                .to_string(),
+            "[1, 2, 3, 4, 5]"
+        );
+
+        let floats = [f32::NAN, f32::NEG_INFINITY, 0.5];
+        assert_eq!(
+            floats
+                .display()
+                .json()
+                .non_finite(NonFinite::String)
+                .to_string(),
+            r#"["NaN", "-inf", 0.5]"#
+        );
+        assert_eq!(
+            format!("{:#}", [true, false].display().json()),
+            "[\n    true,\n    false\n]"
+        );
+        assert_eq!(
+            nested
+                .display()
+                .json()
+                .pretty(true)
+                .indent(2)
+                .should_space(false)
+                .to_string(),
+            "[\n  [-1],\n  [null,2]\n]"
+        );
+    }
+}
@@ -1,116 +1,347 @@
 #![no_std]
 #![doc = include_str!("../README.md")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-use core::fmt::{Display, Write};
+#[macro_use]
+mod style;
+#[cfg(feature = "ansi")]
+mod ansi;
+mod assert;
+mod csv;
+mod diff;
+mod grid;
+mod hexdump;
+mod iter;
+mod json;
+mod map;
+mod quote;
+mod radix;
+mod ranges;
+mod runs;
+mod shell;
+mod spec;
+mod table;
+
+use core::{
+    fmt::{Debug, Display, Formatter},
+    marker::PhantomData,
+};
+
+#[cfg(feature = "ansi")]
+pub use ansi::Ansi;
+pub use csv::{CsvDocument, CsvRecord};
+pub use diff::{diff, SliceDiff};
+pub use grid::GridDisplay;
+pub use hexdump::HexDump;
+pub use iter::{iter_display, IterDisplay};
+pub use json::{Json, JsonDisplay, JsonValue, NonFinite};
+pub use map::{KeyValue, MapDisplay, MapDisplayImpl};
+pub use quote::{Quote, Quoted};
+pub use radix::{Radix, RadixInteger};
+pub use ranges::{Integer, RangeDisplay};
+pub use runs::RunLength;
+pub use shell::{ShellArg, ShellCommand};
+use style::Style;
+pub use table::{Border, Column, Table};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::assert::assert_slice_failed;
+}
 
 /// Configurable Display implementation for slices and Vecs.
-pub trait SliceDisplay<'a, T: Display> {
+pub trait SliceDisplay<'a, T> {
     #[must_use = "this does not display the slice, \
                   it returns an object that can be displayed"]
     fn display(&'a self) -> SliceDisplayImpl<'a, T>;
-}
 
-/// Helper struct for printing Vecs and slices.
-#[derive(Clone, Copy)]
-pub struct SliceDisplayImpl<'a, T: Display> {
-    slice: &'a [T],
-    terminators: (char, char),
-    delimiter: char,
-    should_space: bool,
-}
+    /// Displays the slice using the [`Debug`] implementation of its elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let options = [Some(1), None];
+    ///
+    /// assert_eq!(options.debug_display().to_string(), "[Some(1), None]");
+    /// assert_eq!(
+    ///     options.debug_display().delimiter(';').to_string(),
+    ///     "[Some(1); None]"
+    /// );
+    /// ```
+    #[must_use = "this does not display the slice, \
+                  it returns an object that can be displayed"]
+    fn debug_display(&'a self) -> SliceDisplayImpl<'a, T, WithDebug>;
 
-impl<'a, T: Display> SliceDisplayImpl<'a, T> {
-    /// Configures the terminators to be used for the display.
+    /// Displays a slice of slices, such as a `Vec<Vec<T>>`.
+    ///
+    /// Every level uses the default configuration, see
+    /// [`SliceDisplayImpl::nested`] to customize the inner ones.
     ///
     /// # Example
     ///
     /// ```rust
     /// use slicedisplay::SliceDisplay;
     ///
-    /// let hello: Vec<_> = "Hello".chars().collect();
+    /// let adjacency = vec![vec![1, 2], vec![], vec![0]];
     ///
-    /// assert_eq!(hello.display().terminator('{', '}').to_string(), "{H, e, l, l, o}");
+    /// assert_eq!(adjacency.nested_display().to_string(), "[[1, 2], [], [0]]");
     /// ```
-    pub fn terminator(self, beginning: char, ending: char) -> Self {
-        Self {
-            terminators: (beginning, ending),
-            ..self
+    #[must_use = "this does not display the slice, \
+                  it returns an object that can be displayed"]
+    fn nested_display<U>(&'a self) -> SliceDisplayImpl<'a, T, Nested<'a, U>>
+    where
+        T: AsRef<[U]>,
+        U: 'a;
+
+    /// Displays bytes as a hex dump, see [`HexDump`].
+    #[must_use = "this does not display the bytes, \
+                  it returns an object that can be displayed"]
+    fn hexdump(&'a self) -> HexDump<'a>
+    where
+        Self: AsRef<[u8]>;
+}
+
+/// Defines how each element of a slice is written.
+pub trait ElementFormat<T> {
+    /// Writes a single element into the formatter.
+    fn fmt_element(&self, elem: &T, f: &mut Formatter<'_>) -> core::fmt::Result;
+}
+
+/// Formats elements through their [`Display`] implementation.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WithDisplay;
+
+/// Formats elements through their [`Debug`] implementation.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WithDebug;
+
+impl<T: Display> ElementFormat<T> for WithDisplay {
+    fn fmt_element(&self, elem: &T, f: &mut Formatter<'_>) -> core::fmt::Result {
+        elem.fmt(f)
+    }
+}
+
+impl<T: Debug> ElementFormat<T> for WithDebug {
+    fn fmt_element(&self, elem: &T, f: &mut Formatter<'_>) -> core::fmt::Result {
+        elem.fmt(f)
+    }
+}
+
+impl<T, C> ElementFormat<T> for C
+where
+    C: Fn(&T, &mut Formatter<'_>) -> core::fmt::Result,
+{
+    fn fmt_element(&self, elem: &T, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self(elem, f)
+    }
+}
+
+/// Helper struct for printing Vecs and slices.
+#[derive(Clone, Copy)]
+pub struct SliceDisplayImpl<'a, T, F = WithDisplay> {
+    slice: &'a [T],
+    format: F,
+    style: Style<'a>,
+    #[cfg(feature = "ansi")]
+    paint: Option<ansi::PaintFn<'a, T>>,
+}
+
+impl<'a, T, F> SliceDisplayImpl<'a, T, F> {
+    style_builders!();
+}
+
+impl<'a, T, A> SliceDisplay<'a, T> for A
+where
+    A: AsRef<[T]>,
+{
+    fn display(&'a self) -> SliceDisplayImpl<'a, T> {
+        SliceDisplayImpl {
+            slice: self.as_ref(),
+            format: WithDisplay,
+            style: Style::default(),
+            #[cfg(feature = "ansi")]
+            paint: None,
         }
     }
 
-    /// Configures the delimiter to be used for the display.
+    fn debug_display(&'a self) -> SliceDisplayImpl<'a, T, WithDebug> {
+        self.display().with_format(WithDebug)
+    }
+
+    fn nested_display<U>(&'a self) -> SliceDisplayImpl<'a, T, Nested<'a, U>>
+    where
+        T: AsRef<[U]>,
+        U: 'a,
+    {
+        self.display().nested(|inner| inner)
+    }
+
+    fn hexdump(&'a self) -> HexDump<'a>
+    where
+        Self: AsRef<[u8]>,
+    {
+        HexDump::new(AsRef::<[u8]>::as_ref(self))
+    }
+}
+
+impl<'a, T, F> SliceDisplayImpl<'a, T, F> {
+    /// Keeps the current configuration while changing how elements are written.
+    fn with_format<G>(self, format: G) -> SliceDisplayImpl<'a, T, G> {
+        SliceDisplayImpl {
+            slice: self.slice,
+            format,
+            style: self.style,
+            #[cfg(feature = "ansi")]
+            paint: self.paint,
+        }
+    }
+
+    /// Writes each element through `format` instead of its
+    /// [`Display`] implementation.
     ///
     /// # Example
     ///
     /// ```rust
+    /// use std::fmt::Write;
+    ///
     /// use slicedisplay::SliceDisplay;
     ///
-    /// let hello: Vec<_> = "Hello".chars().collect();
+    /// struct User {
+    ///     name: &'static str,
+    /// }
     ///
-    /// assert_eq!(hello.display().delimiter(';').to_string(), "[H; e; l; l; o]");
+    /// let bytes = [0x1f, 0xa0];
+    /// let users = [User { name: "ana" }, User { name: "bo" }];
+    ///
+    /// assert_eq!(
+    ///     bytes.display().map_display(|byte, f| write!(f, "{byte:#x}")).to_string(),
+    ///     "[0x1f, 0xa0]"
+    /// );
+    /// assert_eq!(
+    ///     users
+    ///         .display()
+    ///         .map_display(|user, f| write!(f, "'{}'", user.name))
+    ///         .to_string(),
+    ///     "['ana', 'bo']"
+    /// );
     /// ```
-    pub fn delimiter(self, delimiter: char) -> Self {
-        Self { delimiter, ..self }
+    pub fn map_display<G>(self, format: G) -> SliceDisplayImpl<'a, T, G>
+    where
+        G: Fn(&T, &mut Formatter<'_>) -> core::fmt::Result,
+    {
+        self.with_format(format)
     }
 
-    /// Sets whether additional spacing should be added between elements.
+    /// Displays each element as a slice of its own, configured by `configure`.
     ///
-    /// True by default.
+    /// The configuration is applied to every inner slice, and `nested` can be
+    /// called again from within `configure` to reach deeper levels.
     ///
     /// # Example
     ///
     /// ```rust
     /// use slicedisplay::SliceDisplay;
     ///
-    /// let hello: Vec<_> = "Hello".chars().collect();
+    /// let matrix = vec![vec![1, 2], vec![3]];
     ///
-    /// assert_eq!(hello.display().delimiter(';').to_string(), "[H; e; l; l; o]");
-    /// assert_eq!(hello.display().delimiter(';').should_space(false).to_string(), "[H;e;l;l;o]");
+    /// assert_eq!(
+    ///     matrix
+    ///         .display()
+    ///         .terminator('{', '}')
+    ///         .nested(|row| row.terminator('(', ')'))
+    ///         .to_string(),
+    ///     "{(1, 2), (3)}"
+    /// );
     /// ```
-    pub fn should_space(self, should_space: bool) -> Self {
-        Self {
-            should_space,
-            ..self
-        }
+    pub fn nested<U, G>(
+        self,
+        configure: impl FnOnce(SliceDisplayImpl<'a, U>) -> SliceDisplayImpl<'a, U, G>,
+    ) -> SliceDisplayImpl<'a, T, Nested<'a, U, G>>
+    where
+        T: AsRef<[U]>,
+        U: 'a,
+    {
+        let inner = configure(SliceDisplayImpl {
+            slice: &[],
+            format: WithDisplay,
+            style: Style::default(),
+            #[cfg(feature = "ansi")]
+            paint: None,
+        });
+
+        self.with_format(Nested {
+            format: inner.format,
+            style: inner.style,
+            #[cfg(feature = "ansi")]
+            paint: inner.paint,
+            _elements: PhantomData,
+        })
     }
 }
 
-impl<T: Display, A> SliceDisplay<'_, T> for A
+/// Formats elements that are slices themselves, see
+/// [`SliceDisplayImpl::nested`].
+#[derive(Clone, Copy)]
+pub struct Nested<'a, U, G = WithDisplay> {
+    format: G,
+    style: Style<'a>,
+    #[cfg(feature = "ansi")]
+    paint: Option<ansi::PaintFn<'a, U>>,
+    _elements: PhantomData<fn(&U)>,
+}
+
+impl<C, U, G> ElementFormat<C> for Nested<'_, U, G>
 where
-    A: AsRef<[T]>,
+    C: AsRef<[U]>,
+    G: ElementFormat<U>,
 {
-    fn display(&self) -> SliceDisplayImpl<'_, T> {
-        SliceDisplayImpl {
-            slice: self.as_ref(),
-            terminators: ('[', ']'),
-            delimiter: ',',
-            should_space: true,
-        }
+    fn fmt_element(&self, elem: &C, f: &mut Formatter<'_>) -> core::fmt::Result {
+        #[cfg(feature = "ansi")]
+        return self
+            .style
+            .fmt_slice(elem.as_ref(), &self.format, self.paint, f);
+
+        #[cfg(not(feature = "ansi"))]
+        self.style.fmt_slice(elem.as_ref(), &self.format, f)
     }
 }
 
-impl<'a, T: Display> Display for SliceDisplayImpl<'a, T> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let (beginning, ending) = self.terminators;
-        let delimiter = self.delimiter;
-        let spacing = if self.should_space { " " } else { "" };
-
-        f.write_char(beginning)?;
-        if let Some((last, elems)) = self.slice.split_last() {
-            for elem in elems {
-                write!(f, "{elem}{delimiter}{spacing}")?;
-            }
-            write!(f, "{last}")?;
-        }
+/// Formats every element with the options of the outer formatter (fill,
+/// alignment, width, precision, sign). The alternate flag (`{:#}`) further
+/// switches to a multi-line layout, one element per line, and is forwarded
+/// as well, so [`debug_display`](SliceDisplay::debug_display) formats
+/// elements with `{:#?}`.
+///
+/// # Example
+///
+/// ```rust
+/// use slicedisplay::SliceDisplay;
+///
+/// let floats = [1.0, 2.25, 3.5];
+///
+/// assert_eq!(format!("{:.2}", floats.display()), "[1.00, 2.25, 3.50]");
+/// assert_eq!(format!("{:>5}", floats.display()), "[    1,  2.25,   3.5]");
+/// assert_eq!(format!("{:#}", floats.display()), "[\n    1,\n    2.25,\n    3.5,\n]");
+/// ```
+impl<'a, T, F: ElementFormat<T>> Display for SliceDisplayImpl<'a, T, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        #[cfg(feature = "ansi")]
+        return self
+            .style
+            .fmt_slice(self.slice, &self.format, self.paint, f);
 
-        f.write_char(ending)
+        #[cfg(not(feature = "ansi"))]
+        self.style.fmt_slice(self.slice, &self.format, f)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use alloc::{string::ToString, vec::Vec};
+    use alloc::{format, string::ToString, vec, vec::Vec};
 
     use crate::SliceDisplay;
 
@@ -175,4 +406,299 @@ mod tests {
             "{1;2;3;4;5}"
         );
     }
+
+    #[test]
+    fn slice_display_str_punctuation() {
+        let numbers = [1, 2, 3];
+        assert_eq!(
+            numbers
+                .display()
+                .terminator_str("<<", ">>")
+                .delimiter_str(" |")
+                .to_string(),
+            "<<1 | 2 | 3>>"
+        );
+        assert_eq!(
+            numbers
+                .display()
+                .no_terminators()
+                .delimiter_str("")
+                .should_space(false)
+                .to_string(),
+            "123"
+        );
+        assert_eq!(
+            format!(
+                "{:#}",
+                numbers.display().terminator_str("", "").delimiter_str(";;")
+            ),
+            "\n    1;;\n    2;;\n    3;;\n"
+        );
+    }
+
+    #[test]
+    fn slice_display_truncate() {
+        let numbers: Vec<u32> = (1..=10_000).collect();
+        assert_eq!(
+            numbers.display().truncate(3, 1).to_string(),
+            "[1, 2, 3, ... (9_996 more) ..., 10000]"
+        );
+        assert_eq!(
+            numbers
+                .display()
+                .truncate(0, 0)
+                .show_omitted(false)
+                .to_string(),
+            "[...]"
+        );
+        assert_eq!(
+            (&numbers[..5]).display().truncate(3, 2).to_string(),
+            "[1, 2, 3, 4, 5]"
+        );
+        assert_eq!(
+            numbers
+                .display()
+                .truncate(1, 0)
+                .ellipsis("..")
+                .should_space(false)
+                .to_string(),
+            "[1,.. (9_999 more) ..]"
+        );
+    }
+
+    #[test]
+    fn slice_display_conjunction() {
+        let words = ["a", "b", "c", "d"];
+        let or = |words: &[&str]| {
+            words
+                .display()
+                .no_terminators()
+                .conjunction("or")
+                .to_string()
+        };
+
+        assert_eq!(or(&[]), "");
+        assert_eq!(or(&words[..1]), "a");
+        assert_eq!(or(&words[..2]), "a or b");
+        assert_eq!(or(&words[..3]), "a, b, or c");
+        assert_eq!(
+            words
+                .display()
+                .conjunction("and")
+                .oxford_comma(false)
+                .truncate(1, 1)
+                .to_string(),
+            "[a, ... (2 more) ... and d]"
+        );
+        assert_eq!(
+            (&words[..3])
+                .display()
+                .conjunction("and")
+                .truncate(1, 0)
+                .to_string(),
+            "[a, ... (2 more) ...]"
+        );
+        assert_eq!(
+            format!(
+                "{:#}",
+                ["a", "b", "c"].display().conjunction("and").truncate(1, 0)
+            ),
+            "[\n    a,\n    ... (2 more) ...,\n]"
+        );
+        assert_eq!(
+            format!("{:#}", ["a", "b"].display().conjunction("and")),
+            "[\n    a,\n    and b,\n]"
+        );
+    }
+
+    #[test]
+    fn slice_display_nested() {
+        let matrix = [[1, 2, 3], [4, 5, 6]];
+        assert_eq!(
+            matrix.nested_display().to_string(),
+            "[[1, 2, 3], [4, 5, 6]]"
+        );
+        assert_eq!(
+            matrix
+                .display()
+                .terminator('{', '}')
+                .delimiter(';')
+                .nested(|row| row.terminator('(', ')').should_space(false))
+                .to_string(),
+            "{(1,2,3); (4,5,6)}"
+        );
+
+        let cube = vec![vec![vec!['a', 'b'], vec![]], vec![vec!['c']]];
+        assert_eq!(
+            cube.display()
+                .nested(|plane| plane
+                    .terminator('{', '}')
+                    .nested(|row| row.no_terminators().delimiter_str("")))
+                .to_string(),
+            "[{a b, }, {c}]"
+        );
+        assert_eq!(
+            matrix
+                .display()
+                .nested(|row| row.truncate(1, 1).show_omitted(false))
+                .to_string(),
+            "[[1, ..., 3], [4, ..., 6]]"
+        );
+    }
+
+    #[test]
+    fn slice_display_map_display() {
+        struct Point(i32, i32);
+
+        let points = [Point(1, 2), Point(-3, 4)];
+        assert_eq!(
+            points
+                .display()
+                .map_display(|Point(x, y), f| write!(f, "({x}, {y})"))
+                .delimiter(';')
+                .to_string(),
+            "[(1, 2); (-3, 4)]"
+        );
+
+        let numbers = [1.0, 2.5];
+        assert_eq!(
+            format!(
+                "{:.1}",
+                numbers
+                    .display()
+                    .map_display(|n, f| core::fmt::Display::fmt(&(n * 2.0), f))
+            ),
+            "[2.0, 5.0]"
+        );
+    }
+
+    #[test]
+    fn slice_display_pretty() {
+        let words = ["one", "two"];
+        assert_eq!(
+            words
+                .display()
+                .pretty(true)
+                .indent(2)
+                .trailing_delimiter(false)
+                .to_string(),
+            "[\n  one,\n  two\n]"
+        );
+        assert_eq!(
+            format!("{:->#5}", words.display().indent(1)),
+            "[\n --one,\n --two,\n]"
+        );
+
+        let options = [Some((1, 2)), None];
+        assert_eq!(
+            format!("{:#}", options.debug_display()),
+            "[\n    Some(\n        (\n            1,\n            2,\n        ),\n    ),\n    None,\n]"
+        );
+
+        let nested = vec![vec![vec![1], vec![]], vec![]];
+        assert_eq!(
+            format!(
+                "{:#}",
+                nested.display().nested(|plane| plane.nested(|row| row))
+            ),
+            "[\n    [\n        [\n            1,\n        ],\n        [],\n    ],\n    [],\n]"
+        );
+
+        assert_eq!(
+            format!("{:#}", [1, 2, 3, 4].display().group(2).indent(1)),
+            "[\n 1,\n 2,\n 3,\n 4,\n]"
+        );
+    }
+
+    #[test]
+    fn slice_display_wrap() {
+        let numbers: Vec<u32> = (1..=12).collect();
+        assert_eq!(
+            numbers.display().wrap(12).indent(2).to_string(),
+            "[1, 2, 3, 4,\n  5, 6, 7,\n  8, 9, 10,\n  11, 12]"
+        );
+        assert_eq!(
+            format!("{:>3}", numbers.display().wrap(18).indent(1)),
+            "[  1,   2,   3,\n   4,   5,   6,\n   7,   8,   9,\n  10,  11,  12]"
+        );
+        assert_eq!(
+            numbers.display().wrap(1).truncate(1, 1).to_string(),
+            "[1,\n    ... (10 more) ...,\n    12]"
+        );
+        assert_eq!(
+            format!(
+                "{:#}",
+                numbers[..2].iter().collect::<Vec<_>>().display().wrap(1)
+            ),
+            "[\n    1,\n    2,\n]"
+        );
+    }
+
+    #[test]
+    fn slice_display_indices() {
+        let numbers: Vec<u32> = (10..20).collect();
+        assert_eq!(
+            numbers
+                .display()
+                .index_format("[", "]=")
+                .index_offset(100)
+                .truncate(1, 2)
+                .to_string(),
+            "[[100]=10, ... (7 more) ..., [108]=18, [109]=19]"
+        );
+        assert_eq!(
+            format!("{:#03}", [7, 8].display().with_indices()),
+            "[\n    0: 007,\n    1: 008,\n]"
+        );
+        assert_eq!(
+            numbers
+                .display()
+                .with_indices()
+                .wrap(24)
+                .indent(1)
+                .to_string(),
+            "[0: 10, 1: 11, 2: 12,\n 3: 13, 4: 14, 5: 15,\n 6: 16, 7: 17, 8: 18,\n 9: 19]"
+        );
+    }
+
+    #[test]
+    fn slice_display_forwards_formatter_options() {
+        let floats = [1.0, -2.5, 3.125];
+        assert_eq!(format!("{:+.2}", floats.display()), "[+1.00, -2.50, +3.12]");
+        assert_eq!(
+            format!("{:*^7.1}", floats.display().delimiter(';')),
+            "[**1.0**; *-2.5**; **3.1**]"
+        );
+
+        let numbers = [1, 20, 300];
+        assert_eq!(format!("{:03}", numbers.display()), "[001, 020, 300]");
+        assert_eq!(
+            format!("{:#}", numbers.display().truncate(1, 1)),
+            "[\n    1,\n    ... (1 more) ...,\n    300,\n]"
+        );
+
+        let empty: [u8; 0] = [];
+        assert_eq!(format!("{:#}", empty.display()), "[]");
+    }
+
+    #[test]
+    fn slice_debug_display() {
+        let pairs = [("a", 1), ("b", 2)];
+        assert_eq!(pairs.debug_display().to_string(), r#"[("a", 1), ("b", 2)]"#);
+        assert_eq!(
+            pairs
+                .debug_display()
+                .terminator('<', '>')
+                .delimiter('|')
+                .should_space(false)
+                .to_string(),
+            r#"<("a", 1)|("b", 2)>"#
+        );
+
+        let options = [Some(1.5), None];
+        assert_eq!(
+            format!("{:.2}", options.debug_display()),
+            "[Some(1.50), None]"
+        );
+    }
 }
@@ -0,0 +1,261 @@
+use alloc::{
+    collections::{btree_map, BTreeMap},
+    vec::Vec,
+};
+use core::{
+    fmt::{Display, Formatter},
+    slice,
+};
+
+use crate::{
+    spec::Adapter,
+    style::{Style, Token},
+    ElementFormat, WithDebug, WithDisplay,
+};
+
+/// Configurable Display implementation for maps and slices of pairs.
+pub trait MapDisplay<'a> {
+    /// Iterator over the key-value pairs of the map.
+    type Iter: Iterator + Clone;
+
+    #[must_use = "this does not display the map, \
+                  it returns an object that can be displayed"]
+    fn display_map(&'a self) -> MapDisplayImpl<'a, Self::Iter>;
+}
+
+/// A key-value pair, as yielded by the iterators of [`MapDisplay`].
+pub trait KeyValue {
+    /// The type of the keys.
+    type Key;
+    /// The type of the values.
+    type Value;
+
+    /// Borrows the key and the value of the pair.
+    fn key_value(&self) -> (&Self::Key, &Self::Value);
+}
+
+impl<K, V> KeyValue for (K, V) {
+    type Key = K;
+    type Value = V;
+
+    fn key_value(&self) -> (&K, &V) {
+        (&self.0, &self.1)
+    }
+}
+
+impl<P: KeyValue> KeyValue for &P {
+    type Key = P::Key;
+    type Value = P::Value;
+
+    fn key_value(&self) -> (&P::Key, &P::Value) {
+        (**self).key_value()
+    }
+}
+
+/// Helper struct for printing maps.
+///
+/// Formatter options, such as precision or width, apply to the values only.
+#[derive(Clone, Copy)]
+pub struct MapDisplayImpl<'a, I, F = WithDisplay> {
+    iter: I,
+    format: F,
+    style: Style<'a>,
+    separator: &'a str,
+}
+
+impl<'a, I> MapDisplayImpl<'a, I> {
+    fn new(iter: I) -> Self {
+        Self {
+            iter,
+            format: WithDisplay,
+            style: Style {
+                terminators: (Token::Char('{'), Token::Char('}')),
+                ..Style::default()
+            },
+            separator: ": ",
+        }
+    }
+}
+
+impl<'a, I, F> MapDisplayImpl<'a, I, F> {
+    style_builders!(
+        terminator,
+        terminator_str,
+        no_terminators,
+        delimiter,
+        delimiter_str,
+        should_space,
+        truncate,
+        ellipsis,
+        show_omitted,
+        pretty,
+        indent,
+        trailing_delimiter,
+        wrap,
+        paint_terminators,
+        paint_delimiters,
+        paint_elements,
+        no_color
+    );
+
+    /// Configures the separator between keys and values.
+    ///
+    /// `": "` by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    ///
+    /// use slicedisplay::MapDisplay;
+    ///
+    /// let map = BTreeMap::from([("a", 1), ("b", 2)]);
+    ///
+    /// assert_eq!(map.display_map().key_value_separator("=").to_string(), "{a=1, b=2}");
+    /// assert_eq!(
+    ///     map.display_map().key_value_separator(" => ").to_string(),
+    ///     "{a => 1, b => 2}"
+    /// );
+    /// ```
+    pub fn key_value_separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Displays keys and values using their [`Debug`](core::fmt::Debug)
+    /// implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::MapDisplay;
+    ///
+    /// let pairs = [("a", Some(1)), ("b", None)];
+    ///
+    /// assert_eq!(
+    ///     pairs.display_map().debug().to_string(),
+    ///     r#"{"a": Some(1), "b": None}"#
+    /// );
+    /// ```
+    pub fn debug(self) -> MapDisplayImpl<'a, I, WithDebug> {
+        MapDisplayImpl {
+            iter: self.iter,
+            format: WithDebug,
+            style: self.style,
+            separator: self.separator,
+        }
+    }
+}
+
+impl<I, F> Display for MapDisplayImpl<'_, I, F>
+where
+    I: Iterator + Clone,
+    I::Item: KeyValue,
+    F: ElementFormat<<I::Item as KeyValue>::Key> + ElementFormat<<I::Item as KeyValue>::Value>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.style.fmt_iter(f, self.iter.clone(), |pair, f| {
+            let (key, value) = pair.key_value();
+            // The formatter options are meant for the values, so keys are
+            // written without them.
+            write!(
+                f,
+                "{}",
+                Adapter(|f: &mut Formatter<'_>| self.format.fmt_element(key, f))
+            )?;
+            f.write_str(self.separator)?;
+            self.format.fmt_element(value, f)
+        })
+    }
+}
+
+impl<'a, K: 'a, V: 'a> MapDisplay<'a> for [(K, V)] {
+    type Iter = slice::Iter<'a, (K, V)>;
+
+    fn display_map(&'a self) -> MapDisplayImpl<'a, Self::Iter> {
+        MapDisplayImpl::new(self.iter())
+    }
+}
+
+impl<'a, K: 'a, V: 'a, const N: usize> MapDisplay<'a> for [(K, V); N] {
+    type Iter = slice::Iter<'a, (K, V)>;
+
+    fn display_map(&'a self) -> MapDisplayImpl<'a, Self::Iter> {
+        MapDisplayImpl::new(self.iter())
+    }
+}
+
+impl<'a, K: 'a, V: 'a> MapDisplay<'a> for Vec<(K, V)> {
+    type Iter = slice::Iter<'a, (K, V)>;
+
+    fn display_map(&'a self) -> MapDisplayImpl<'a, Self::Iter> {
+        MapDisplayImpl::new(self.iter())
+    }
+}
+
+impl<'a, K: 'a, V: 'a> MapDisplay<'a> for BTreeMap<K, V> {
+    type Iter = btree_map::Iter<'a, K, V>;
+
+    fn display_map(&'a self) -> MapDisplayImpl<'a, Self::Iter> {
+        MapDisplayImpl::new(self.iter())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, K: 'a, V: 'a, S> MapDisplay<'a> for std::collections::HashMap<K, V, S> {
+    type Iter = std::collections::hash_map::Iter<'a, K, V>;
+
+    fn display_map(&'a self) -> MapDisplayImpl<'a, Self::Iter> {
+        MapDisplayImpl::new(self.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeMap, format, string::ToString, vec};
+
+    use crate::MapDisplay;
+
+    extern crate alloc;
+
+    #[test]
+    fn map_display() {
+        let empty: BTreeMap<u8, u8> = BTreeMap::new();
+        assert_eq!(empty.display_map().to_string(), "{}");
+
+        let map = BTreeMap::from([("b", 2), ("a", 1), ("c", 3)]);
+        assert_eq!(map.display_map().to_string(), "{a: 1, b: 2, c: 3}");
+        assert_eq!(
+            map.display_map()
+                .key_value_separator("=")
+                .delimiter(';')
+                .terminator('(', ')')
+                .truncate(1, 1)
+                .to_string(),
+            "(a=1; ... (1 more) ...; c=3)"
+        );
+
+        let pairs = vec![(1, 'x'), (2, 'y')];
+        assert_eq!(
+            pairs
+                .display_map()
+                .key_value_separator(" => ")
+                .no_terminators()
+                .to_string(),
+            "1 => x, 2 => y"
+        );
+
+        let map = BTreeMap::from([("name", 1.25)]);
+        assert_eq!(format!("{:.1}", map.display_map()), "{name: 1.2}");
+        assert_eq!(format!("{:>6}", map.display_map()), "{name:   1.25}");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn map_display_hash_map() {
+        extern crate std;
+
+        let map = std::collections::HashMap::from([(7, 1.5)]);
+        assert_eq!(std::format!("{:.2}", map.display_map()), "{7: 1.50}");
+    }
+}
@@ -0,0 +1,154 @@
+use core::fmt::{Formatter, Write};
+
+use crate::{spec::Spec, ElementFormat, SliceDisplayImpl};
+
+/// How elements are quoted, see [`SliceDisplayImpl::quote`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Quote {
+    /// Wraps elements in the given character, escaping it and backslashes
+    /// with a backslash.
+    Char(char),
+    /// Wraps elements in double quotes, escaping them like a Rust string
+    /// literal.
+    Rust,
+    /// Wraps elements in single quotes for a POSIX shell, writing inner
+    /// single quotes as `'\''`.
+    Shell,
+    /// Wraps elements in single quotes for SQL, doubling inner single quotes.
+    Sql,
+}
+
+impl Quote {
+    fn quote_char(self) -> char {
+        match self {
+            Quote::Char(c) => c,
+            Quote::Rust => '"',
+            Quote::Shell | Quote::Sql => '\'',
+        }
+    }
+
+    pub(crate) fn write_escaped(self, out: &mut impl Write, c: char) -> core::fmt::Result {
+        match (self, c) {
+            (Quote::Char(quote), c) if c == quote || c == '\\' => write!(out, "\\{c}"),
+            (Quote::Rust, '"' | '\\') => write!(out, "\\{c}"),
+            (Quote::Rust, '\n') => out.write_str("\\n"),
+            (Quote::Rust, '\r') => out.write_str("\\r"),
+            (Quote::Rust, '\t') => out.write_str("\\t"),
+            (Quote::Rust, '\0') => out.write_str("\\0"),
+            (Quote::Rust, c) if c.is_control() => write!(out, "\\u{{{:x}}}", c as u32),
+            (Quote::Shell, '\'') => out.write_str("'\\''"),
+            (Quote::Sql, '\'') => out.write_str("''"),
+            (_, c) => out.write_char(c),
+        }
+    }
+}
+
+/// Escapes the text written into it.
+struct Escaper<'w, W> {
+    out: &'w mut W,
+    quote: Quote,
+}
+
+impl<W: Write> Write for Escaper<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        s.chars()
+            .try_for_each(|c| self.quote.write_escaped(self.out, c))
+    }
+}
+
+/// Quotes the elements written by another [`ElementFormat`], see
+/// [`SliceDisplayImpl::quote`].
+#[derive(Clone, Copy, Debug)]
+pub struct Quoted<F> {
+    quote: Quote,
+    format: F,
+}
+
+impl<T, F: ElementFormat<T>> ElementFormat<T> for Quoted<F> {
+    fn fmt_element(&self, elem: &T, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let quote = self.quote.quote_char();
+        let spec = Spec::of(f);
+
+        f.write_char(quote)?;
+        spec.write(
+            &mut Escaper {
+                out: f,
+                quote: self.quote,
+            },
+            |f| self.format.fmt_element(elem, f),
+        )?;
+        f.write_char(quote)
+    }
+}
+
+impl<'a, T, F> SliceDisplayImpl<'a, T, F> {
+    /// Wraps each element in quotes, escaping the characters that would
+    /// otherwise make the output ambiguous.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::{Quote, SliceDisplay};
+    ///
+    /// let args = ["ls", "my file", "it's"];
+    ///
+    /// assert_eq!(
+    ///     args.display().quote(Quote::Rust).to_string(),
+    ///     r#"["ls", "my file", "it's"]"#
+    /// );
+    /// assert_eq!(
+    ///     args.display().no_terminators().delimiter_str("").quote(Quote::Shell).to_string(),
+    ///     r"'ls' 'my file' 'it'\''s'"
+    /// );
+    /// assert_eq!(
+    ///     args.display().terminator('(', ')').quote(Quote::Sql).to_string(),
+    ///     "('ls', 'my file', 'it''s')"
+    /// );
+    /// ```
+    pub fn quote(self, quote: Quote) -> SliceDisplayImpl<'a, T, Quoted<F>> {
+        SliceDisplayImpl {
+            slice: self.slice,
+            format: Quoted {
+                quote,
+                format: self.format,
+            },
+            style: self.style,
+            #[cfg(feature = "ansi")]
+            paint: self.paint,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString};
+
+    use crate::{Quote, SliceDisplay};
+
+    extern crate alloc;
+
+    #[test]
+    fn quote_display() {
+        let words = ["a, b", "\"q\"\\", "tab\tnul\0bell\u{7}é"];
+        assert_eq!(
+            words.display().quote(Quote::Rust).to_string(),
+            r#"["a, b", "\"q\"\\", "tab\tnul\0bell\u{7}é"]"#
+        );
+        assert_eq!(
+            words.display().quote(Quote::Char('`')).to_string(),
+            "[`a, b`, `\"q\"\\\\`, `tab\tnul\0bell\u{7}é`]"
+        );
+        assert_eq!(
+            ["`"].display().quote(Quote::Char('`')).to_string(),
+            r"[`\``]"
+        );
+        assert_eq!(
+            format!("{:>3}", [1, 22].display().quote(Quote::Sql)),
+            "['  1', ' 22']"
+        );
+        assert_eq!(
+            [0xff_u8].display().hex().quote(Quote::Rust).to_string(),
+            r#"["0xff"]"#
+        );
+    }
+}
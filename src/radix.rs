@@ -0,0 +1,258 @@
+use core::fmt::{Binary, Formatter, LowerHex, Octal, UpperHex};
+
+use crate::{ElementFormat, SliceDisplayImpl};
+
+/// Formats integers in base 2, 8 or 16, see [`SliceDisplayImpl::hex`].
+#[derive(Clone, Copy, Debug)]
+pub struct Radix {
+    base: Base,
+    prefix: bool,
+    zero_pad: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Base {
+    Binary,
+    Octal,
+    LowerHex,
+    UpperHex,
+}
+
+impl Radix {
+    fn new(base: Base) -> Self {
+        Self {
+            base,
+            prefix: true,
+            zero_pad: false,
+        }
+    }
+
+    /// Amount of digits needed to write any value of `T`.
+    fn digits<T: RadixInteger>(&self) -> usize {
+        let bits = T::BITS as usize;
+        match self.base {
+            Base::Binary => bits,
+            Base::Octal => (bits + 2) / 3,
+            Base::LowerHex | Base::UpperHex => bits / 4,
+        }
+    }
+}
+
+/// Integers that can be written in base 2, 8 or 16, see
+/// [`SliceDisplayImpl::hex`].
+pub trait RadixInteger: Binary + Octal + LowerHex + UpperHex {
+    /// The size of the integer type, in bits.
+    const BITS: u32;
+}
+
+macro_rules! impl_radix_integer {
+    ($($t:ty),*) => {
+        $(
+            impl RadixInteger for $t {
+                const BITS: u32 = <$t>::BITS;
+            }
+        )*
+    };
+}
+
+impl_radix_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T: RadixInteger + ?Sized> RadixInteger for &T {
+    const BITS: u32 = T::BITS;
+}
+
+impl<T: RadixInteger> ElementFormat<T> for Radix {
+    fn fmt_element(&self, elem: &T, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let width = match (self.zero_pad, self.prefix) {
+            (false, _) => 0,
+            (true, false) => self.digits::<T>(),
+            (true, true) => self.digits::<T>() + 2,
+        };
+
+        match (self.base, self.prefix) {
+            (Base::Binary, false) => write!(f, "{elem:0width$b}"),
+            (Base::Binary, true) => write!(f, "{elem:#0width$b}"),
+            (Base::Octal, false) => write!(f, "{elem:0width$o}"),
+            (Base::Octal, true) => write!(f, "{elem:#0width$o}"),
+            (Base::LowerHex, false) => write!(f, "{elem:0width$x}"),
+            (Base::LowerHex, true) => write!(f, "{elem:#0width$x}"),
+            (Base::UpperHex, false) => write!(f, "{elem:0width$X}"),
+            (Base::UpperHex, true) => write!(f, "{elem:#0width$X}"),
+        }
+    }
+}
+
+impl<'a, T, F> SliceDisplayImpl<'a, T, F> {
+    /// Displays integers in lowercase hexadecimal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let bytes = [0x1f_u8, 0xa0, 0x01];
+    ///
+    /// assert_eq!(bytes.display().hex().to_string(), "[0x1f, 0xa0, 0x1]");
+    /// assert_eq!(
+    ///     bytes.display().hex().prefix(false).zero_pad(true).to_string(),
+    ///     "[1f, a0, 01]"
+    /// );
+    /// ```
+    pub fn hex(self) -> SliceDisplayImpl<'a, T, Radix> {
+        self.with_format(Radix::new(Base::LowerHex))
+    }
+
+    /// Displays integers in uppercase hexadecimal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let bytes = [0x1f_u8, 0xa0];
+    ///
+    /// assert_eq!(bytes.display().upper_hex().to_string(), "[0x1F, 0xA0]");
+    /// ```
+    pub fn upper_hex(self) -> SliceDisplayImpl<'a, T, Radix> {
+        self.with_format(Radix::new(Base::UpperHex))
+    }
+
+    /// Displays integers in binary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let bytes = [5_u8, 255];
+    ///
+    /// assert_eq!(bytes.display().binary().to_string(), "[0b101, 0b11111111]");
+    /// ```
+    pub fn binary(self) -> SliceDisplayImpl<'a, T, Radix> {
+        self.with_format(Radix::new(Base::Binary))
+    }
+
+    /// Displays integers in octal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let modes = [0o755_u16, 0o644];
+    ///
+    /// assert_eq!(modes.display().octal().to_string(), "[0o755, 0o644]");
+    /// ```
+    pub fn octal(self) -> SliceDisplayImpl<'a, T, Radix> {
+        self.with_format(Radix::new(Base::Octal))
+    }
+}
+
+impl<'a, T> SliceDisplayImpl<'a, T, Radix> {
+    /// Sets whether the `0x`, `0o` or `0b` prefix should be written.
+    ///
+    /// True by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let bytes = [0x1f_u8, 0xa0];
+    ///
+    /// assert_eq!(bytes.display().hex().prefix(false).to_string(), "[1f, a0]");
+    /// ```
+    pub fn prefix(mut self, prefix: bool) -> Self {
+        self.format.prefix = prefix;
+        self
+    }
+
+    /// Sets whether integers should be padded with zeroes up to the amount
+    /// of digits of their type, e.g. two hexadecimal digits for `u8`.
+    ///
+    /// False by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let numbers = [1_u16, 0x2a];
+    ///
+    /// assert_eq!(
+    ///     numbers.display().hex().zero_pad(true).to_string(),
+    ///     "[0x0001, 0x002a]"
+    /// );
+    /// ```
+    pub fn zero_pad(mut self, zero_pad: bool) -> Self {
+        self.format.zero_pad = zero_pad;
+        self
+    }
+
+    /// Writes the zero-padded digits one after another, without prefixes,
+    /// delimiters or terminators.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let bytes = [0xde_u8, 0xad, 0xbe, 0xef, 0x01];
+    ///
+    /// assert_eq!(bytes.display().hex().compact().to_string(), "deadbeef01");
+    /// assert_eq!(
+    ///     bytes.display().upper_hex().compact().group(2).to_string(),
+    ///     "DEAD BEEF 01"
+    /// );
+    /// ```
+    pub fn compact(self) -> Self {
+        self.prefix(false)
+            .zero_pad(true)
+            .no_terminators()
+            .delimiter_str("")
+            .should_space(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::SliceDisplay;
+
+    extern crate alloc;
+
+    #[test]
+    fn radix_display() {
+        let words = [0x1_u16, 0xbeef];
+        assert_eq!(
+            words.display().hex().zero_pad(true).to_string(),
+            "[0x0001, 0xbeef]"
+        );
+        assert_eq!(
+            words.display().binary().compact().group(1).to_string(),
+            "0000000000000001 1011111011101111"
+        );
+        assert_eq!(
+            [0o7_u8, 0o377].display().octal().zero_pad(true).to_string(),
+            "[0o007, 0o377]"
+        );
+        assert_eq!([-1_i8].display().hex().to_string(), "[0xff]");
+        assert_eq!(
+            [&1_u8, &0xff].display().hex().zero_pad(true).to_string(),
+            "[0x01, 0xff]"
+        );
+        assert_eq!(
+            (0_u8..10)
+                .collect::<alloc::vec::Vec<_>>()
+                .display()
+                .hex()
+                .compact()
+                .group(4)
+                .truncate(5, 2)
+                .show_omitted(false)
+                .to_string(),
+            "00010203 04... 0809"
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{style::Style, ElementFormat, SliceDisplayImpl};
+
+/// Integers whose consecutive values can be compressed into ranges, see
+/// [`SliceDisplayImpl::ranges`].
+pub trait Integer: Copy {
+    /// Returns how far `next` is past `self`, or `None` if `next` is not
+    /// greater than `self`.
+    fn distance(self, next: Self) -> Option<u128>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn distance(self, next: Self) -> Option<u128> {
+                    next.checked_sub(self)
+                        .filter(|distance| *distance > 0)
+                        .map(|distance| distance as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Displays a slice of integers with consecutive values compressed into
+/// ranges, see [`SliceDisplayImpl::ranges`].
+#[derive(Clone, Copy)]
+pub struct RangeDisplay<'a, T, F> {
+    slice: &'a [T],
+    format: F,
+    style: Style<'a>,
+    range_separator: &'a str,
+    stride: bool,
+    step_separator: &'a str,
+}
+
+/// A range of evenly spaced elements, starting at `start`.
+struct Range<'a, T> {
+    first: &'a T,
+    last: &'a T,
+    step: u128,
+    start: usize,
+    len: usize,
+}
+
+/// Splits a slice into ranges, and single elements in between.
+struct Ranges<'a, T> {
+    slice: &'a [T],
+    start: usize,
+    stride: bool,
+}
+
+impl<T> Clone for Ranges<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            start: self.start,
+            stride: self.stride,
+        }
+    }
+}
+
+impl<'a, T: Integer> Iterator for Ranges<'a, T> {
+    type Item = Range<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.slice.first()?;
+        let step = match self.slice.get(1).and_then(|next| first.distance(*next)) {
+            Some(step) if step == 1 || self.stride => step,
+            _ => 0,
+        };
+        let len = match step {
+            0 => 1,
+            _ => {
+                let steps = self
+                    .slice
+                    .windows(2)
+                    .take_while(|pair| pair[0].distance(pair[1]) == Some(step))
+                    .count();
+                // Two elements a stride apart are clearer written as is.
+                match step {
+                    1 => steps + 1,
+                    _ if steps >= 2 => steps + 1,
+                    _ => 1,
+                }
+            }
+        };
+
+        let range = Range {
+            first,
+            last: &self.slice[len - 1],
+            step: if len > 1 { step } else { 0 },
+            start: self.start,
+            len,
+        };
+        self.slice = &self.slice[len..];
+        self.start += len;
+
+        Some(range)
+    }
+}
+
+impl<'a, T: Integer, F> SliceDisplayImpl<'a, T, F> {
+    /// Compresses runs of consecutive integers into ranges, as in `1-5`.
+    ///
+    /// Both ends of a range are always included, whatever the separator.
+    /// Values that are not greater than the one before them start a new
+    /// range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let cpus = [0, 1, 2, 3, 8, 10, 11];
+    ///
+    /// assert_eq!(
+    ///     cpus.display().ranges().no_terminators().delimiter_str(",").should_space(false).to_string(),
+    ///     "0-3,8,10-11"
+    /// );
+    /// ```
+    pub fn ranges(self) -> RangeDisplay<'a, T, F> {
+        RangeDisplay {
+            slice: self.slice,
+            format: self.format,
+            style: self.style,
+            range_separator: "-",
+            stride: false,
+            step_separator: " step ",
+        }
+    }
+}
+
+impl<'a, T, F> RangeDisplay<'a, T, F> {
+    style_builders!(
+        terminator,
+        terminator_str,
+        no_terminators,
+        delimiter,
+        delimiter_str,
+        should_space,
+        truncate,
+        ellipsis,
+        show_omitted,
+        conjunction,
+        oxford_comma,
+        pretty,
+        indent,
+        trailing_delimiter,
+        wrap,
+        with_indices,
+        index_format,
+        index_offset,
+        paint_terminators,
+        paint_delimiters,
+        paint_indices,
+        paint_elements,
+        no_color
+    );
+
+    /// Configures the separator between the ends of a range.
+    ///
+    /// `"-"` by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let pages = [1, 2, 3, 7];
+    ///
+    /// assert_eq!(pages.display().ranges().range_separator("..=").to_string(), "[1..=3, 7]");
+    /// ```
+    pub fn range_separator(mut self, separator: &'a str) -> Self {
+        self.range_separator = separator;
+        self
+    }
+
+    /// Sets whether evenly spaced values should be compressed as well,
+    /// writing the step after the range.
+    ///
+    /// Only runs of at least three values are compressed this way. False by
+    /// default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let even = [0, 2, 4, 6, 8, 9];
+    ///
+    /// assert_eq!(
+    ///     even.display().ranges().range_separator("..=").stride(true).to_string(),
+    ///     "[0..=8 step 2, 9]"
+    /// );
+    /// ```
+    pub fn stride(mut self, stride: bool) -> Self {
+        self.stride = stride;
+        self
+    }
+
+    /// Configures the text written between a range and its step.
+    ///
+    /// `" step "` by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let odd = [1, 3, 5, 7];
+    ///
+    /// assert_eq!(
+    ///     odd.display().ranges().stride(true).step_separator(" by ").to_string(),
+    ///     "[1-7 by 2]"
+    /// );
+    /// ```
+    pub fn step_separator(mut self, separator: &'a str) -> Self {
+        self.step_separator = separator;
+        self
+    }
+}
+
+impl<T: Integer, F: ElementFormat<T>> Display for RangeDisplay<'_, T, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let ranges = Ranges {
+            slice: self.slice,
+            start: 0,
+            stride: self.stride,
+        };
+
+        // Indices count elements rather than ranges.
+        self.style.fmt_iter_weighted(
+            f,
+            ranges,
+            |range| range.len,
+            |range, _, f| self.style.fmt_index(f, range.start),
+            |range, f| {
+                self.format.fmt_element(range.first, f)?;
+                if range.len > 1 {
+                    f.write_str(self.range_separator)?;
+                    self.format.fmt_element(range.last, f)?;
+                }
+                if range.step > 1 {
+                    write!(f, "{}{}", self.step_separator, range.step)?;
+                }
+
+                Ok(())
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::SliceDisplay;
+
+    extern crate alloc;
+
+    #[test]
+    fn range_display() {
+        let numbers = [-3, -2, -1, 1, 3, 5, 7, 7, 8, 20, 10];
+        assert_eq!(
+            numbers.display().ranges().range_separator("..").to_string(),
+            "[-3..-1, 1, 3, 5, 7, 7..8, 20, 10]"
+        );
+        assert_eq!(
+            numbers
+                .display()
+                .ranges()
+                .stride(true)
+                .with_indices()
+                .to_string(),
+            "[0: -3--1, 3: 1-7 step 2, 7: 7-8, 9: 20, 10: 10]"
+        );
+        assert_eq!(
+            [0_u8, 1, 2, 255].display().hex().ranges().to_string(),
+            "[0x0-0x2, 0xff]"
+        );
+        assert_eq!(
+            [i128::MIN, 0, i128::MAX].display().ranges().stride(true).to_string(),
+            "[-170141183460469231731687303715884105728, 0, 170141183460469231731687303715884105727]"
+        );
+        assert_eq!(
+            [1, 3, 4, 5].display().ranges().stride(true).to_string(),
+            "[1, 3-5]"
+        );
+        assert_eq!(
+            [1, 2, 3, 4, 5, 6, 10, 20]
+                .display()
+                .ranges()
+                .truncate(0, 1)
+                .to_string(),
+            "[... (7 more) ..., 20]"
+        );
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn range_display_ansi() {
+        use crate::Ansi;
+
+        assert_eq!(
+            [1, 2, 3, 7]
+                .display()
+                .ranges()
+                .with_indices()
+                .paint_indices(Ansi::CYAN)
+                .paint_elements(Ansi::BOLD)
+                .to_string(),
+            "[\x1b[36m0: \x1b[0m\x1b[1m1-3\x1b[0m, \x1b[36m3: \x1b[0m\x1b[1m7\x1b[0m]"
+        );
+    }
+}
@@ -0,0 +1,251 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{style::Style, ElementFormat, SliceDisplayImpl};
+
+/// Displays a slice with consecutive equal elements collapsed, see
+/// [`SliceDisplayImpl::collapse_runs`].
+#[derive(Clone, Copy)]
+pub struct RunLength<'a, T, F> {
+    slice: &'a [T],
+    format: F,
+    style: Style<'a>,
+    min_run: usize,
+    repeat: (&'a str, &'a str),
+}
+
+/// A run of equal elements, starting at `start`.
+struct Run<'a, T> {
+    elem: &'a T,
+    start: usize,
+    len: usize,
+}
+
+/// Splits a slice into runs of at least `min_run` equal elements, and single
+/// elements in between.
+struct Runs<'a, T> {
+    slice: &'a [T],
+    start: usize,
+    min_run: usize,
+}
+
+impl<T> Clone for Runs<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            start: self.start,
+            min_run: self.min_run,
+        }
+    }
+}
+
+impl<'a, T: PartialEq> Iterator for Runs<'a, T> {
+    type Item = Run<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (elem, rest) = self.slice.split_first()?;
+        let repeated = rest.iter().take_while(|other| *other == elem).count() + 1;
+        let len = if repeated >= self.min_run {
+            repeated
+        } else {
+            1
+        };
+
+        let run = Run {
+            elem,
+            start: self.start,
+            len,
+        };
+        self.slice = &self.slice[len..];
+        self.start += len;
+
+        Some(run)
+    }
+}
+
+impl<'a, T: PartialEq, F> SliceDisplayImpl<'a, T, F> {
+    /// Collapses runs of consecutive equal elements into a single element
+    /// followed by the length of the run.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let samples = [0, 0, 0, 0, 7, 1, 1, 0, 0, 0];
+    ///
+    /// assert_eq!(
+    ///     samples.display().collapse_runs().to_string(),
+    ///     "[0 × 4, 7, 1, 1, 0 × 3]"
+    /// );
+    /// ```
+    pub fn collapse_runs(self) -> RunLength<'a, T, F> {
+        RunLength {
+            slice: self.slice,
+            format: self.format,
+            style: self.style,
+            min_run: 3,
+            repeat: (" × ", ""),
+        }
+    }
+}
+
+impl<'a, T, F> RunLength<'a, T, F> {
+    style_builders!(
+        terminator,
+        terminator_str,
+        no_terminators,
+        delimiter,
+        delimiter_str,
+        should_space,
+        truncate,
+        ellipsis,
+        show_omitted,
+        conjunction,
+        oxford_comma,
+        pretty,
+        indent,
+        trailing_delimiter,
+        wrap,
+        with_indices,
+        index_format,
+        index_offset,
+        paint_terminators,
+        paint_delimiters,
+        paint_indices,
+        paint_elements,
+        no_color
+    );
+
+    /// Configures the shortest run to be collapsed.
+    ///
+    /// 3 by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let samples = [0, 0, 1];
+    ///
+    /// assert_eq!(samples.display().collapse_runs().min_run(2).to_string(), "[0 × 2, 1]");
+    /// ```
+    pub fn min_run(mut self, min_run: usize) -> Self {
+        self.min_run = min_run;
+        self
+    }
+
+    /// Configures the text written around the length of collapsed runs.
+    ///
+    /// `" × "` and `""` by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let samples = [0; 512];
+    ///
+    /// assert_eq!(
+    ///     samples
+    ///         .display()
+    ///         .collapse_runs()
+    ///         .repeat_format(" (repeated ", " times)")
+    ///         .to_string(),
+    ///     "[0 (repeated 512 times)]"
+    /// );
+    /// ```
+    pub fn repeat_format(mut self, prefix: &'a str, suffix: &'a str) -> Self {
+        self.repeat = (prefix, suffix);
+        self
+    }
+}
+
+impl<T: PartialEq, F: ElementFormat<T>> Display for RunLength<'_, T, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let runs = Runs {
+            slice: self.slice,
+            start: 0,
+            min_run: self.min_run.max(1),
+        };
+        let (prefix, suffix) = self.repeat;
+
+        // Indices count elements rather than runs.
+        self.style.fmt_iter_weighted(
+            f,
+            runs,
+            |run| run.len,
+            |run, _, f| self.style.fmt_index(f, run.start),
+            |run, f| {
+                self.format.fmt_element(run.elem, f)?;
+                if run.len >= self.min_run.max(2) {
+                    write!(f, "{prefix}{}{suffix}", run.len)?;
+                }
+
+                Ok(())
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString};
+
+    use crate::SliceDisplay;
+
+    extern crate alloc;
+
+    #[test]
+    fn run_length_display() {
+        let samples = [5, 5, 5, 0, 0, 0, 0, 0, 0, 1, 2, 2];
+        assert_eq!(
+            samples.display().collapse_runs().with_indices().to_string(),
+            "[0: 5 × 3, 3: 0 × 6, 9: 1, 10: 2, 11: 2]"
+        );
+        assert_eq!(
+            samples.display().collapse_runs().min_run(1).to_string(),
+            "[5 × 3, 0 × 6, 1, 2 × 2]"
+        );
+        assert_eq!(
+            samples
+                .display()
+                .hex()
+                .collapse_runs()
+                .truncate(1, 1)
+                .to_string(),
+            "[0x5 × 3, ... (8 more) ..., 0x2]"
+        );
+        assert_eq!(
+            [0, 0, 0, 0, 0, 0, 1, 2, 3]
+                .display()
+                .collapse_runs()
+                .truncate(0, 1)
+                .to_string(),
+            "[... (8 more) ..., 3]"
+        );
+        assert_eq!(
+            format!("{:03}", [1.0, 1.0, 1.0].display().collapse_runs()),
+            "[001 × 3]"
+        );
+
+        let empty: [u8; 0] = [];
+        assert_eq!(empty.display().collapse_runs().to_string(), "[]");
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn run_length_display_ansi() {
+        use crate::Ansi;
+
+        assert_eq!(
+            [1, 1, 1, 2]
+                .display()
+                .collapse_runs()
+                .with_indices()
+                .paint_indices(Ansi::CYAN)
+                .paint_elements(Ansi::BOLD)
+                .to_string(),
+            "[\x1b[36m0: \x1b[0m\x1b[1m1 × 3\x1b[0m, \x1b[36m3: \x1b[0m\x1b[1m2\x1b[0m]"
+        );
+    }
+}
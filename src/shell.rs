@@ -0,0 +1,189 @@
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Write};
+
+use crate::{quote::Quote, SliceDisplayImpl};
+
+/// Displays a slice as the arguments of a POSIX shell command line, see
+/// [`SliceDisplayImpl::shell`].
+///
+/// It has no layout options, as the shell would misread their output.
+#[derive(Clone, Copy)]
+pub struct ShellCommand<'a, T> {
+    slice: &'a [T],
+}
+
+/// Values that can be written as a single shell argument.
+///
+/// Implemented for strings, and for `OsStr` and `Path` with the `std`
+/// feature. Those are converted lossily, so arguments that are not valid
+/// UTF-8 are written with U+FFFD in place of the invalid bytes, and do not
+/// round-trip.
+pub trait ShellArg {
+    /// Writes the value as a single argument, quoted if needed.
+    fn fmt_shell(&self, f: &mut Formatter<'_>) -> core::fmt::Result;
+}
+
+impl<'a, T, F> SliceDisplayImpl<'a, T, F> {
+    /// Displays the slice as a shell command line, which can be pasted back
+    /// into a POSIX shell.
+    ///
+    /// Elements are separated by spaces, and single-quoted only when they
+    /// contain characters the shell would interpret, or are empty. Every
+    /// other option of the display, such as truncation or the multi-line
+    /// layout, is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::SliceDisplay;
+    ///
+    /// let args = ["grep", "-r", "it's $HOME", "*.rs", ""];
+    ///
+    /// assert_eq!(
+    ///     args.display().shell().to_string(),
+    ///     r"grep -r 'it'\''s $HOME' '*.rs' ''"
+    /// );
+    /// ```
+    pub fn shell(self) -> ShellCommand<'a, T> {
+        ShellCommand { slice: self.slice }
+    }
+}
+
+impl<T: ShellArg> Display for ShellCommand<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for (index, arg) in self.slice.iter().enumerate() {
+            if index > 0 {
+                f.write_char(' ')?;
+            }
+            arg.fmt_shell(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether `c` can appear unquoted in a shell argument.
+///
+/// `=` is left out, as a leading `NAME=value` word would be read as a variable
+/// assignment rather than an argument.
+fn is_safe(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '@' | '%' | '+' | ':' | ',' | '.' | '/' | '-' | '_')
+}
+
+fn fmt_shell_str(s: &str, f: &mut Formatter<'_>) -> core::fmt::Result {
+    if !s.is_empty() && s.chars().all(is_safe) {
+        return f.write_str(s);
+    }
+
+    f.write_char('\'')?;
+    s.chars()
+        .try_for_each(|c| Quote::Shell.write_escaped(f, c))?;
+    f.write_char('\'')
+}
+
+impl ShellArg for str {
+    fn fmt_shell(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        fmt_shell_str(self, f)
+    }
+}
+
+impl ShellArg for String {
+    fn fmt_shell(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        fmt_shell_str(self, f)
+    }
+}
+
+impl<T: ShellArg + ?Sized> ShellArg for &T {
+    fn fmt_shell(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        (**self).fmt_shell(f)
+    }
+}
+
+#[cfg(feature = "std")]
+mod os {
+    use core::fmt::Formatter;
+    use std::{
+        ffi::{OsStr, OsString},
+        path::{Path, PathBuf},
+    };
+
+    use super::{fmt_shell_str, ShellArg};
+
+    impl ShellArg for OsStr {
+        fn fmt_shell(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            fmt_shell_str(&self.to_string_lossy(), f)
+        }
+    }
+
+    impl ShellArg for OsString {
+        fn fmt_shell(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            self.as_os_str().fmt_shell(f)
+        }
+    }
+
+    impl ShellArg for Path {
+        fn fmt_shell(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            self.as_os_str().fmt_shell(f)
+        }
+    }
+
+    impl ShellArg for PathBuf {
+        fn fmt_shell(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            self.as_os_str().fmt_shell(f)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString, vec};
+
+    use crate::SliceDisplay;
+
+    extern crate alloc;
+
+    #[test]
+    fn shell_display() {
+        let args = vec![
+            "echo".to_string(),
+            "--name=a.b,c@d".to_string(),
+            "two words".to_string(),
+            "$PATH".to_string(),
+            "\"'\"".to_string(),
+            "~".to_string(),
+            "".to_string(),
+        ];
+        assert_eq!(
+            args.display().shell().to_string(),
+            r#"echo '--name=a.b,c@d' 'two words' '$PATH' '"'\''"' '~' ''"#
+        );
+        assert_eq!(
+            ["FOO=bar", "x"].display().shell().to_string(),
+            "'FOO=bar' x"
+        );
+        assert_eq!(
+            format!(
+                "{:#}",
+                ["a b", "c", "d"]
+                    .display()
+                    .truncate(1, 0)
+                    .with_indices()
+                    .delimiter(';')
+                    .conjunction("and")
+                    .group(2)
+                    .wrap(4)
+                    .shell()
+            ),
+            "'a b' c d"
+        );
+
+        #[cfg(feature = "std")]
+        {
+            let paths = [std::path::PathBuf::from("/tmp/my dir"), "a/b".into()];
+            assert_eq!(paths.display().shell().to_string(), "'/tmp/my dir' a/b");
+
+            let args = [std::ffi::OsString::from("*")];
+            assert_eq!(args.display().shell().to_string(), "'*'");
+        }
+    }
+}
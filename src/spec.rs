@@ -0,0 +1,232 @@
+use core::fmt::{Alignment, Display, Formatter, Write};
+
+/// The options of a [`Formatter`], captured to be applied again when
+/// writing into something other than that formatter.
+#[derive(Clone, Copy)]
+pub(crate) struct Spec {
+    fill: char,
+    align: Option<Alignment>,
+    width: Option<usize>,
+    precision: Option<usize>,
+    sign_plus: bool,
+    alternate: bool,
+    zero_pad: bool,
+}
+
+/// Displays through a closure, so that it can be handed to `write!`.
+pub(crate) struct Adapter<F>(pub(crate) F);
+
+impl<F> Display for Adapter<F>
+where
+    F: Fn(&mut Formatter<'_>) -> core::fmt::Result,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        (self.0)(f)
+    }
+}
+
+/// Writes `$value` with the given flags, a width of `$width` (zero meaning
+/// no padding) and zero-padding if `$zero` is `"0"`.
+macro_rules! write_flagged {
+    ($out:expr, $value:expr, $spec:expr, $width:expr, $zero:literal) => {
+        match ($spec.sign_plus, $spec.alternate, $spec.precision) {
+            (false, false, None) => write!($out, concat!("{:", $zero, "w$}"), $value, w = $width),
+            (false, false, Some(p)) => {
+                write!(
+                    $out,
+                    concat!("{:", $zero, "w$.p$}"),
+                    $value,
+                    w = $width,
+                    p = p
+                )
+            }
+            (false, true, None) => write!($out, concat!("{:#", $zero, "w$}"), $value, w = $width),
+            (false, true, Some(p)) => {
+                write!(
+                    $out,
+                    concat!("{:#", $zero, "w$.p$}"),
+                    $value,
+                    w = $width,
+                    p = p
+                )
+            }
+            (true, false, None) => write!($out, concat!("{:+", $zero, "w$}"), $value, w = $width),
+            (true, false, Some(p)) => {
+                write!(
+                    $out,
+                    concat!("{:+", $zero, "w$.p$}"),
+                    $value,
+                    w = $width,
+                    p = p
+                )
+            }
+            (true, true, None) => write!($out, concat!("{:+#", $zero, "w$}"), $value, w = $width),
+            (true, true, Some(p)) => {
+                write!(
+                    $out,
+                    concat!("{:+#", $zero, "w$.p$}"),
+                    $value,
+                    w = $width,
+                    p = p
+                )
+            }
+        }
+    };
+}
+
+impl Spec {
+    pub(crate) fn of(f: &Formatter<'_>) -> Self {
+        Self {
+            fill: f.fill(),
+            align: f.align(),
+            width: f.width(),
+            precision: f.precision(),
+            sign_plus: f.sign_plus(),
+            alternate: f.alternate(),
+            zero_pad: f.sign_aware_zero_pad(),
+        }
+    }
+
+    /// Runs `fmt` against a formatter with these options, writing into `out`.
+    pub(crate) fn write<W, F>(&self, out: &mut W, fmt: F) -> core::fmt::Result
+    where
+        W: Write,
+        F: Fn(&mut Formatter<'_>) -> core::fmt::Result,
+    {
+        let value = Adapter(fmt);
+
+        match (self.width, self.align) {
+            (Some(width), _) if self.zero_pad => write_flagged!(out, value, self, width, "0"),
+            (Some(width), None) => write_flagged!(out, value, self, width, ""),
+            (Some(width), Some(align)) => {
+                let mut counter = Counter(0);
+                write_flagged!(counter, value, self, 0, "")?;
+
+                let padding = width.saturating_sub(counter.0);
+                let (before, after) = match align {
+                    Alignment::Left => (0, padding),
+                    Alignment::Center => (padding / 2, padding - padding / 2),
+                    Alignment::Right => (padding, 0),
+                };
+
+                self.fill_with(out, before)?;
+                write_flagged!(out, value, self, 0, "")?;
+                self.fill_with(out, after)
+            }
+            (None, _) => write_flagged!(out, value, self, 0, ""),
+        }
+    }
+
+    fn fill_with<W: Write>(&self, out: &mut W, count: usize) -> core::fmt::Result {
+        (0..count).try_for_each(|_| out.write_char(self.fill))
+    }
+}
+
+/// Counts the characters written into it.
+pub(crate) struct Counter(pub(crate) usize);
+
+impl Write for Counter {
+    #[cfg(not(feature = "ansi"))]
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+
+    /// Skips ANSI escape codes, which take no room once displayed.
+    #[cfg(feature = "ansi")]
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let mut escaped = false;
+        for c in s.chars() {
+            match c {
+                '\x1b' => escaped = true,
+                'm' if escaped => escaped = false,
+                _ if escaped => {}
+                _ => self.0 += 1,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Indents every line written into it, except for the first one.
+pub(crate) struct Indented<'w, W> {
+    out: &'w mut W,
+    indent: usize,
+    on_newline: bool,
+}
+
+impl<'w, W: Write> Indented<'w, W> {
+    pub(crate) fn new(out: &'w mut W, indent: usize) -> Self {
+        Self {
+            out,
+            indent,
+            on_newline: false,
+        }
+    }
+}
+
+impl<W: Write> Write for Indented<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for line in s.split_inclusive('\n') {
+            if self.on_newline && line != "\n" {
+                write!(self.out, "{:1$}", "", self.indent)?;
+            }
+            self.out.write_str(line)?;
+            self.on_newline = line.ends_with('\n');
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String};
+    use core::fmt::{Display, Formatter};
+
+    use super::{Indented, Spec};
+
+    extern crate alloc;
+
+    /// Re-applies the outer options through an indenting writer.
+    struct Forwarded<T>(T);
+
+    impl<T: Display> Display for Forwarded<T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            let mut out = String::new();
+            Spec::of(f).write(&mut Indented::new(&mut out, 2), |f| self.0.fmt(f))?;
+            f.write_str(&out)
+        }
+    }
+
+    #[test]
+    fn spec_forwards_options() {
+        for (spec, direct, forwarded) in [
+            (
+                "{:+.2}",
+                format!("{:+.2}", 1.5),
+                format!("{:+.2}", Forwarded(1.5)),
+            ),
+            (
+                "{:08.3}",
+                format!("{:08.3}", -1.5),
+                format!("{:08.3}", Forwarded(-1.5)),
+            ),
+            (
+                "{:>6}",
+                format!("{:>6}", "ab"),
+                format!("{:>6}", Forwarded("ab")),
+            ),
+            (
+                "{:*^7}",
+                format!("{:*^7}", 42),
+                format!("{:*^7}", Forwarded(42)),
+            ),
+            ("{:6}", format!("{:6}", 42), format!("{:6}", Forwarded(42))),
+        ] {
+            assert_eq!(direct, forwarded, "{spec}");
+        }
+
+        assert_eq!(format!("{}", Forwarded("a\nb\n\nc")), "a\n  b\n\n  c");
+    }
+}
@@ -0,0 +1,1202 @@
+use core::fmt::{Display, Formatter, Write};
+
+#[cfg(feature = "ansi")]
+use crate::ansi::{Ansi, PaintFn, Palette};
+use crate::{
+    spec::{Adapter, Counter, Indented, Spec},
+    ElementFormat,
+};
+
+/// Layout settings shared by the displays of this crate.
+#[derive(Clone, Copy)]
+pub(crate) struct Style<'a> {
+    pub(crate) terminators: (Token<'a>, Token<'a>),
+    pub(crate) delimiter: Token<'a>,
+    pub(crate) should_space: bool,
+    pub(crate) truncate: Option<(usize, usize)>,
+    pub(crate) ellipsis: &'a str,
+    pub(crate) show_omitted: bool,
+    pub(crate) conjunction: Option<&'a str>,
+    pub(crate) oxford_comma: bool,
+    pub(crate) group: Option<usize>,
+    pub(crate) pretty: bool,
+    pub(crate) indent: usize,
+    pub(crate) trailing_delimiter: bool,
+    pub(crate) wrap: Option<usize>,
+    pub(crate) indices: Option<(&'a str, &'a str)>,
+    pub(crate) index_offset: usize,
+    #[cfg(feature = "ansi")]
+    pub(crate) palette: Palette,
+}
+
+impl Default for Style<'_> {
+    fn default() -> Self {
+        Self {
+            terminators: (Token::Char('['), Token::Char(']')),
+            delimiter: Token::Char(','),
+            should_space: true,
+            truncate: None,
+            ellipsis: "...",
+            show_omitted: true,
+            conjunction: None,
+            oxford_comma: true,
+            group: None,
+            pretty: false,
+            indent: 4,
+            trailing_delimiter: true,
+            wrap: None,
+            indices: None,
+            index_offset: 0,
+            #[cfg(feature = "ansi")]
+            palette: Palette::default(),
+        }
+    }
+}
+
+/// A piece of punctuation, either a single character or a borrowed string.
+#[derive(Clone, Copy)]
+pub(crate) enum Token<'a> {
+    Char(char),
+    Str(&'a str),
+}
+
+impl Display for Token<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Token::Char(c) => f.write_char(c),
+            Token::Str(s) => f.write_str(s),
+        }
+    }
+}
+
+/// Expands to the builder methods configuring a `style: Style<'a>` field.
+///
+/// Without arguments, every builder is expanded along with its examples, for
+/// `SliceDisplayImpl`. Other displays list the builders that apply to them,
+/// which are documented by a link to the `SliceDisplayImpl` ones.
+macro_rules! style_builders {
+    (@builder $mode:ident terminator) => {
+        style_builders!(@emit $mode
+            {
+                /// Configures the terminators to be used for the display.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let hello: Vec<_> = "Hello".chars().collect();
+                ///
+                /// assert_eq!(hello.display().terminator('{', '}').to_string(), "{H, e, l, l, o}");
+                /// ```
+            }
+            {
+                /// Configures the terminators to be used for the display.
+                ///
+                /// See [`SliceDisplayImpl::terminator`](crate::SliceDisplayImpl::terminator).
+            }
+            pub fn terminator(mut self, beginning: char, ending: char) -> Self {
+                self.style.terminators = (
+                    $crate::style::Token::Char(beginning),
+                    $crate::style::Token::Char(ending),
+                );
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident terminator_str) => {
+        style_builders!(@emit $mode
+            {
+                /// Configures string terminators to be used for the display.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let numbers = [1, 2, 3];
+                ///
+                /// assert_eq!(numbers.display().terminator_str("<<", ">>").to_string(), "<<1, 2, 3>>");
+                /// ```
+            }
+            {
+                /// Configures string terminators to be used for the display.
+                ///
+                /// See [`SliceDisplayImpl::terminator_str`](crate::SliceDisplayImpl::terminator_str).
+            }
+            pub fn terminator_str(mut self, beginning: &'a str, ending: &'a str) -> Self {
+                self.style.terminators = (
+                    $crate::style::Token::Str(beginning),
+                    $crate::style::Token::Str(ending),
+                );
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident no_terminators) => {
+        style_builders!(@emit $mode
+            {
+                /// Removes the terminators, displaying the bare elements.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let letters = ['a', 'b', 'c'];
+                ///
+                /// assert_eq!(letters.display().no_terminators().to_string(), "a, b, c");
+                /// ```
+            }
+            {
+                /// Removes the terminators, displaying the bare elements.
+                ///
+                /// See [`SliceDisplayImpl::no_terminators`](crate::SliceDisplayImpl::no_terminators).
+            }
+            pub fn no_terminators(self) -> Self {
+                self.terminator_str("", "")
+            }
+        );
+    };
+
+    (@builder $mode:ident delimiter) => {
+        style_builders!(@emit $mode
+            {
+                /// Configures the delimiter to be used for the display.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let hello: Vec<_> = "Hello".chars().collect();
+                ///
+                /// assert_eq!(hello.display().delimiter(';').to_string(), "[H; e; l; l; o]");
+                /// ```
+            }
+            {
+                /// Configures the delimiter to be used for the display.
+                ///
+                /// See [`SliceDisplayImpl::delimiter`](crate::SliceDisplayImpl::delimiter).
+            }
+            pub fn delimiter(mut self, delimiter: char) -> Self {
+                self.style.delimiter = $crate::style::Token::Char(delimiter);
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident delimiter_str) => {
+        style_builders!(@emit $mode
+            {
+                /// Configures a string delimiter to be used for the display.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let hello: Vec<_> = "Hello".chars().collect();
+                ///
+                /// assert_eq!(hello.display().delimiter_str(" |").to_string(), "[H | e | l | l | o]");
+                /// assert_eq!(
+                ///     hello.display().delimiter_str(" -> ").should_space(false).to_string(),
+                ///     "[H -> e -> l -> l -> o]"
+                /// );
+                /// ```
+            }
+            {
+                /// Configures a string delimiter to be used for the display.
+                ///
+                /// See [`SliceDisplayImpl::delimiter_str`](crate::SliceDisplayImpl::delimiter_str).
+            }
+            pub fn delimiter_str(mut self, delimiter: &'a str) -> Self {
+                self.style.delimiter = $crate::style::Token::Str(delimiter);
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident should_space) => {
+        style_builders!(@emit $mode
+            {
+                /// Sets whether additional spacing should be added between elements.
+                ///
+                /// True by default.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let hello: Vec<_> = "Hello".chars().collect();
+                ///
+                /// assert_eq!(hello.display().delimiter(';').to_string(), "[H; e; l; l; o]");
+                /// assert_eq!(hello.display().delimiter(';').should_space(false).to_string(), "[H;e;l;l;o]");
+                /// ```
+            }
+            {
+                /// Sets whether additional spacing should be added between elements.
+                ///
+                /// See [`SliceDisplayImpl::should_space`](crate::SliceDisplayImpl::should_space).
+            }
+            pub fn should_space(mut self, should_space: bool) -> Self {
+                self.style.should_space = should_space;
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident truncate) => {
+        style_builders!(@emit $mode
+            {
+                /// Only displays the first `head` and the last `tail` elements, replacing
+                /// the ones in between with a placeholder.
+                ///
+                /// Has no effect if the slice holds at most `head + tail` elements.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let numbers: Vec<u32> = (1..=10_000).collect();
+                ///
+                /// assert_eq!(
+                ///     numbers.display().truncate(2, 2).to_string(),
+                ///     "[1, 2, ... (9_996 more) ..., 9999, 10000]"
+                /// );
+                /// ```
+            }
+            {
+                /// Only displays the first `head` and the last `tail` elements, replacing
+                /// the ones in between with a placeholder.
+                ///
+                /// See [`SliceDisplayImpl::truncate`](crate::SliceDisplayImpl::truncate).
+            }
+            pub fn truncate(mut self, head: usize, tail: usize) -> Self {
+                self.style.truncate = Some((head, tail));
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident ellipsis) => {
+        style_builders!(@emit $mode
+            {
+                /// Configures the placeholder written in place of the elements omitted by
+                /// [`truncate`](Self::truncate).
+                ///
+                /// `"..."` by default.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let numbers = [1, 2, 3, 4, 5];
+                ///
+                /// assert_eq!(
+                ///     numbers.display().truncate(1, 1).ellipsis("…").to_string(),
+                ///     "[1, … (3 more) …, 5]"
+                /// );
+                /// ```
+            }
+            {
+                /// Configures the placeholder written in place of the elements omitted by
+                /// [`truncate`](crate::SliceDisplayImpl::truncate).
+                ///
+                /// See [`SliceDisplayImpl::ellipsis`](crate::SliceDisplayImpl::ellipsis).
+            }
+            pub fn ellipsis(mut self, ellipsis: &'a str) -> Self {
+                self.style.ellipsis = ellipsis;
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident show_omitted) => {
+        style_builders!(@emit $mode
+            {
+                /// Sets whether the amount of elements omitted by
+                /// [`truncate`](Self::truncate) should be displayed.
+                ///
+                /// True by default.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let numbers = [1, 2, 3, 4, 5];
+                ///
+                /// assert_eq!(
+                ///     numbers.display().truncate(1, 1).show_omitted(false).to_string(),
+                ///     "[1, ..., 5]"
+                /// );
+                /// ```
+            }
+            {
+                /// Sets whether the amount of elements omitted by
+                /// [`truncate`](crate::SliceDisplayImpl::truncate) should be displayed.
+                ///
+                /// See [`SliceDisplayImpl::show_omitted`](crate::SliceDisplayImpl::show_omitted).
+            }
+            pub fn show_omitted(mut self, show_omitted: bool) -> Self {
+                self.style.show_omitted = show_omitted;
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident conjunction) => {
+        style_builders!(@emit $mode
+            {
+                /// Joins the last element with a conjunction, as in natural language.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let expected = ["a", "b", "c"];
+                ///
+                /// assert_eq!(
+                ///     expected.display().no_terminators().conjunction("or").to_string(),
+                ///     "a, b, or c"
+                /// );
+                /// assert_eq!(
+                ///     ["a", "b"].display().no_terminators().conjunction("or").to_string(),
+                ///     "a or b"
+                /// );
+                /// ```
+            }
+            {
+                /// Joins the last element with a conjunction, as in natural language.
+                ///
+                /// See [`SliceDisplayImpl::conjunction`](crate::SliceDisplayImpl::conjunction).
+            }
+            pub fn conjunction(mut self, conjunction: &'a str) -> Self {
+                self.style.conjunction = Some(conjunction);
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident oxford_comma) => {
+        style_builders!(@emit $mode
+            {
+                /// Sets whether the delimiter should be kept before the
+                /// [`conjunction`](Self::conjunction) of three or more elements.
+                ///
+                /// True by default.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let colors = ["red", "green", "blue"];
+                ///
+                /// assert_eq!(
+                ///     colors
+                ///         .display()
+                ///         .no_terminators()
+                ///         .conjunction("and")
+                ///         .oxford_comma(false)
+                ///         .to_string(),
+                ///     "red, green and blue"
+                /// );
+                /// ```
+            }
+            {
+                /// Sets whether the delimiter should be kept before the
+                /// [`conjunction`](crate::SliceDisplayImpl::conjunction) of three or more elements.
+                ///
+                /// See [`SliceDisplayImpl::oxford_comma`](crate::SliceDisplayImpl::oxford_comma).
+            }
+            pub fn oxford_comma(mut self, oxford_comma: bool) -> Self {
+                self.style.oxford_comma = oxford_comma;
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident group) => {
+        style_builders!(@emit $mode
+            {
+                /// Adds a space before every `size` elements, on top of the delimiter.
+                ///
+                /// Ignored when displaying with `{:#}`.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `size` is zero.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let bytes = [0x1f_u8, 0xa0, 0x00, 0x42];
+                ///
+                /// assert_eq!(bytes.display().hex().compact().group(2).to_string(), "1fa0 0042");
+                /// ```
+            }
+            {
+                /// Adds a space before every `size` elements, on top of the delimiter.
+                ///
+                /// See [`SliceDisplayImpl::group`](crate::SliceDisplayImpl::group).
+            }
+            pub fn group(mut self, size: usize) -> Self {
+                assert!(size > 0, "group size must be greater than zero");
+                self.style.group = Some(size);
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident pretty) => {
+        style_builders!(@emit $mode
+            {
+                /// Sets whether each element should be displayed on its own line.
+                ///
+                /// Also enabled by displaying with `{:#}`. Elements spanning multiple
+                /// lines are indented as well, so that nested structures line up.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let matrix = [[1, 2], [3, 4]];
+                ///
+                /// assert_eq!(
+                ///     matrix.display().pretty(true).nested(|row| row.pretty(true)).to_string(),
+                ///     "[\n    [\n        1,\n        2,\n    ],\n    [\n        3,\n        4,\n    ],\n]"
+                /// );
+                /// ```
+            }
+            {
+                /// Sets whether each element should be displayed on its own line.
+                ///
+                /// See [`SliceDisplayImpl::pretty`](crate::SliceDisplayImpl::pretty).
+            }
+            pub fn pretty(mut self, pretty: bool) -> Self {
+                self.style.pretty = pretty;
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident indent) => {
+        style_builders!(@emit $mode
+            {
+                /// Configures the amount of spaces elements are indented by when
+                /// displayed on their own lines.
+                ///
+                /// 4 by default.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let numbers = [1, 2];
+                ///
+                /// assert_eq!(format!("{:#}", numbers.display().indent(2)), "[\n  1,\n  2,\n]");
+                /// ```
+            }
+            {
+                /// Configures the amount of spaces elements are indented by when
+                /// displayed on their own lines.
+                ///
+                /// See [`SliceDisplayImpl::indent`](crate::SliceDisplayImpl::indent).
+            }
+            pub fn indent(mut self, indent: usize) -> Self {
+                self.style.indent = indent;
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident trailing_delimiter) => {
+        style_builders!(@emit $mode
+            {
+                /// Sets whether the delimiter should also follow the last element
+                /// when displayed on their own lines.
+                ///
+                /// True by default.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let numbers = [1, 2];
+                ///
+                /// assert_eq!(
+                ///     format!("{:#}", numbers.display().trailing_delimiter(false)),
+                ///     "[\n    1,\n    2\n]"
+                /// );
+                /// ```
+            }
+            {
+                /// Sets whether the delimiter should also follow the last element
+                /// when displayed on their own lines.
+                ///
+                /// See [`SliceDisplayImpl::trailing_delimiter`](crate::SliceDisplayImpl::trailing_delimiter).
+            }
+            pub fn trailing_delimiter(mut self, trailing_delimiter: bool) -> Self {
+                self.style.trailing_delimiter = trailing_delimiter;
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident wrap) => {
+        style_builders!(@emit $mode
+            {
+                /// Packs as many elements as fit within `width` columns on each line,
+                /// continuing on new lines indented by [`indent`](Self::indent).
+                ///
+                /// Ignored when displaying each element on its own line.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let numbers: Vec<u32> = (1..=12).collect();
+                ///
+                /// assert_eq!(
+                ///     numbers.display().wrap(20).indent(1).to_string(),
+                ///     "[1, 2, 3, 4, 5, 6,\n 7, 8, 9, 10, 11,\n 12]"
+                /// );
+                /// ```
+            }
+            {
+                /// Packs as many elements as fit within `width` columns on each line,
+                /// continuing on new lines indented by [`indent`](crate::SliceDisplayImpl::indent).
+                ///
+                /// See [`SliceDisplayImpl::wrap`](crate::SliceDisplayImpl::wrap).
+            }
+            pub fn wrap(mut self, width: usize) -> Self {
+                self.style.wrap = Some(width);
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident with_indices) => {
+        style_builders!(@emit $mode
+            {
+                /// Prefixes each element with its index, as in `0: a`.
+                ///
+                /// Along with [`truncate`](Self::truncate), the indices show which
+                /// elements were left out.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let letters = ['a', 'b', 'c', 'd', 'e'];
+                ///
+                /// assert_eq!(letters.display().with_indices().to_string(), "[0: a, 1: b, 2: c, 3: d, 4: e]");
+                /// assert_eq!(
+                ///     letters.display().with_indices().truncate(1, 1).to_string(),
+                ///     "[0: a, ... (3 more) ..., 4: e]"
+                /// );
+                /// ```
+            }
+            {
+                /// Prefixes each element with its index, as in `0: a`.
+                ///
+                /// See [`SliceDisplayImpl::with_indices`](crate::SliceDisplayImpl::with_indices).
+            }
+            pub fn with_indices(self) -> Self {
+                self.index_format("", ": ")
+            }
+        );
+    };
+
+    (@builder $mode:ident index_format) => {
+        style_builders!(@emit $mode
+            {
+                /// Prefixes each element with its index, written between `prefix`
+                /// and `suffix`.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let letters = ['a', 'b'];
+                ///
+                /// assert_eq!(letters.display().index_format("[", "]=").to_string(), "[[0]=a, [1]=b]");
+                /// assert_eq!(letters.display().index_format("#", " ").to_string(), "[#0 a, #1 b]");
+                /// ```
+            }
+            {
+                /// Prefixes each element with its index, written between `prefix`
+                /// and `suffix`.
+                ///
+                /// See [`SliceDisplayImpl::index_format`](crate::SliceDisplayImpl::index_format).
+            }
+            pub fn index_format(mut self, prefix: &'a str, suffix: &'a str) -> Self {
+                self.style.indices = Some((prefix, suffix));
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident index_offset) => {
+        style_builders!(@emit $mode
+            {
+                /// Configures the index of the first element, when displaying
+                /// indices.
+                ///
+                /// 0 by default.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::SliceDisplay;
+                ///
+                /// let lines = ["fn main() {", "}"];
+                ///
+                /// assert_eq!(
+                ///     lines.display().with_indices().index_offset(1).to_string(),
+                ///     "[1: fn main() {, 2: }]"
+                /// );
+                /// ```
+            }
+            {
+                /// Configures the index of the first element, when displaying
+                /// indices.
+                ///
+                /// See [`SliceDisplayImpl::index_offset`](crate::SliceDisplayImpl::index_offset).
+            }
+            pub fn index_offset(mut self, offset: usize) -> Self {
+                self.style.index_offset = offset;
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident paint_terminators) => {
+        style_builders!(@emit $mode
+            {
+                /// Styles the terminators with an ANSI escape code.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::{Ansi, SliceDisplay};
+                ///
+                /// assert_eq!(
+                ///     [1].display().paint_terminators(Ansi::DIM).to_string(),
+                ///     "\x1b[2m[\x1b[0m1\x1b[2m]\x1b[0m"
+                /// );
+                /// ```
+            }
+            {
+                /// Styles the terminators with an ANSI escape code.
+                ///
+                /// See [`SliceDisplayImpl::paint_terminators`](crate::SliceDisplayImpl::paint_terminators).
+            }
+            #[cfg(feature = "ansi")]
+            pub fn paint_terminators(mut self, ansi: $crate::Ansi) -> Self {
+                self.style.palette.terminators = Some(ansi);
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident paint_delimiters) => {
+        style_builders!(@emit $mode
+            {
+                /// Styles the delimiters with an ANSI escape code.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::{Ansi, SliceDisplay};
+                ///
+                /// assert_eq!(
+                ///     [1, 2].display().paint_delimiters(Ansi::DIM).to_string(),
+                ///     "[1\x1b[2m,\x1b[0m 2]"
+                /// );
+                /// ```
+            }
+            {
+                /// Styles the delimiters with an ANSI escape code.
+                ///
+                /// See [`SliceDisplayImpl::paint_delimiters`](crate::SliceDisplayImpl::paint_delimiters).
+            }
+            #[cfg(feature = "ansi")]
+            pub fn paint_delimiters(mut self, ansi: $crate::Ansi) -> Self {
+                self.style.palette.delimiters = Some(ansi);
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident paint_indices) => {
+        style_builders!(@emit $mode
+            {
+                /// Styles the indices added by [`with_indices`](Self::with_indices)
+                /// with an ANSI escape code.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::{Ansi, SliceDisplay};
+                ///
+                /// assert_eq!(
+                ///     ['a'].display().with_indices().paint_indices(Ansi::CYAN).to_string(),
+                ///     "[\x1b[36m0: \x1b[0ma]"
+                /// );
+                /// ```
+            }
+            {
+                /// Styles the indices added by [`with_indices`](crate::SliceDisplayImpl::with_indices)
+                /// with an ANSI escape code.
+                ///
+                /// See [`SliceDisplayImpl::paint_indices`](crate::SliceDisplayImpl::paint_indices).
+            }
+            #[cfg(feature = "ansi")]
+            pub fn paint_indices(mut self, ansi: $crate::Ansi) -> Self {
+                self.style.palette.indices = Some(ansi);
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident paint_elements) => {
+        style_builders!(@emit $mode
+            {
+                /// Styles every element with an ANSI escape code.
+                ///
+                /// Styles are only written when displaying without `{:#}`, and unless
+                /// disabled by [`no_color`](Self::no_color).
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::{Ansi, SliceDisplay};
+                ///
+                /// let numbers = [1, 2];
+                ///
+                /// assert_eq!(
+                ///     numbers.display().paint_elements(Ansi::BOLD).to_string(),
+                ///     "[\x1b[1m1\x1b[0m, \x1b[1m2\x1b[0m]"
+                /// );
+                /// assert_eq!(
+                ///     format!("{:#}", numbers.display().paint_elements(Ansi::BOLD)),
+                ///     "[\n    1,\n    2,\n]"
+                /// );
+                /// ```
+            }
+            {
+                /// Styles every element with an ANSI escape code.
+                ///
+                /// See [`SliceDisplayImpl::paint_elements`](crate::SliceDisplayImpl::paint_elements).
+            }
+            #[cfg(feature = "ansi")]
+            pub fn paint_elements(mut self, ansi: $crate::Ansi) -> Self {
+                self.style.palette.elements = Some(ansi);
+                self
+            }
+        );
+    };
+
+    (@builder $mode:ident no_color) => {
+        style_builders!(@emit $mode
+            {
+                /// Disables every ANSI style, for outputs that are not terminals.
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use slicedisplay::{Ansi, SliceDisplay};
+                ///
+                /// assert_eq!(
+                ///     [1, 2].display().paint_delimiters(Ansi::DIM).no_color().to_string(),
+                ///     "[1, 2]"
+                /// );
+                /// ```
+            }
+            {
+                /// Disables every ANSI style, for outputs that are not terminals.
+                ///
+                /// See [`SliceDisplayImpl::no_color`](crate::SliceDisplayImpl::no_color).
+            }
+            #[cfg(feature = "ansi")]
+            pub fn no_color(mut self) -> Self {
+                self.style.palette.disabled = true;
+                self
+            }
+        );
+    };
+    (@emit full { $($full:tt)* } { $($brief:tt)* } $($item:tt)*) => {
+        $($full)*
+        $($item)*
+    };
+    (@emit brief { $($full:tt)* } { $($brief:tt)* } $($item:tt)*) => {
+        $($brief)*
+        $($item)*
+    };
+    () => {
+        style_builders!(
+            @full
+            terminator,
+            terminator_str,
+            no_terminators,
+            delimiter,
+            delimiter_str,
+            should_space,
+            truncate,
+            ellipsis,
+            show_omitted,
+            conjunction,
+            oxford_comma,
+            group,
+            pretty,
+            indent,
+            trailing_delimiter,
+            wrap,
+            with_indices,
+            index_format,
+            index_offset,
+            paint_terminators,
+            paint_delimiters,
+            paint_indices,
+            paint_elements,
+            no_color
+        );
+    };
+    (@full $($builder:ident),*) => {
+        $(style_builders!(@builder full $builder);)*
+    };
+    ($($builder:ident),* $(,)?) => {
+        $(style_builders!(@builder brief $builder);)*
+    };
+}
+
+/// A part of the display that can be styled.
+#[derive(Clone, Copy)]
+enum Part {
+    Terminators,
+    Delimiters,
+    Indices,
+    Elements,
+}
+
+/// An item of the displayed sequence.
+enum Entry<X> {
+    /// An element, along with its index in the sequence.
+    Element(usize, X),
+    Omitted(usize),
+}
+
+impl<'a> Style<'a> {
+    /// Yields the items to display, replacing the elements left out by
+    /// [`truncate`](crate::SliceDisplayImpl::truncate) with a single
+    /// placeholder, which counts `weight(item)` elements for each of them.
+    fn entries<I: Iterator + Clone>(
+        &self,
+        items: I,
+        weight: impl Fn(&I::Item) -> usize,
+    ) -> impl Iterator<Item = Entry<I::Item>> {
+        let len = match self.truncate {
+            Some(_) => items.clone().count(),
+            None => 0,
+        };
+        let (head, omitted, tail) = match self.truncate {
+            Some((head, tail)) if head.saturating_add(tail) < len => (
+                items.clone().take(head),
+                Some(
+                    items
+                        .clone()
+                        .skip(head)
+                        .take(len - head - tail)
+                        .map(|item| weight(&item))
+                        .sum(),
+                ),
+                Some(items.skip(len - tail).zip(len - tail..)),
+            ),
+            _ => (items.take(usize::MAX), None, None),
+        };
+
+        head.zip(0..)
+            .map(|(elem, index)| Entry::Element(index, elem))
+            .chain(omitted.map(Entry::Omitted))
+            .chain(
+                tail.into_iter()
+                    .flatten()
+                    .map(|(elem, index)| Entry::Element(index, elem)),
+            )
+    }
+
+    fn fmt_placeholder(&self, f: &mut impl Write, omitted: usize) -> core::fmt::Result {
+        let ellipsis = self.ellipsis;
+        f.write_str(ellipsis)?;
+        if self.show_omitted {
+            f.write_str(" (")?;
+            write_grouped(f, omitted)?;
+            write!(f, " more) {ellipsis}")?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes with `write`, in the style configured for `part`.
+    #[cfg(feature = "ansi")]
+    fn paint(
+        &self,
+        f: &mut Formatter<'_>,
+        part: Part,
+        write: impl FnOnce(&mut Formatter<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        let palette = self.palette;
+        let ansi = match part {
+            Part::Terminators => palette.terminators,
+            Part::Delimiters => palette.delimiters,
+            Part::Indices => palette.indices,
+            Part::Elements => palette.elements,
+        };
+        self.paint_with(f, ansi, write)
+    }
+
+    #[cfg(not(feature = "ansi"))]
+    fn paint(
+        &self,
+        f: &mut Formatter<'_>,
+        _part: Part,
+        write: impl FnOnce(&mut Formatter<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        write(f)
+    }
+
+    /// Writes with `write`, in the style `ansi` unless styles are disabled.
+    #[cfg(feature = "ansi")]
+    pub(crate) fn paint_with(
+        &self,
+        f: &mut Formatter<'_>,
+        ansi: Option<Ansi>,
+        write: impl FnOnce(&mut Formatter<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        match ansi.filter(|_| !self.palette.disabled && !f.alternate()) {
+            Some(ansi) => ansi.paint(f, write),
+            None => write(f),
+        }
+    }
+
+    /// Writes the index of an element, if enabled.
+    pub(crate) fn fmt_index(&self, f: &mut Formatter<'_>, index: usize) -> core::fmt::Result {
+        match self.indices {
+            Some((prefix, suffix)) => self.paint(f, Part::Indices, |f| {
+                write!(
+                    f,
+                    "{prefix}{}{suffix}",
+                    index.saturating_add(self.index_offset)
+                )
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Measures how many characters `entry` takes once written.
+    fn entry_width<X>(
+        &self,
+        f: &Formatter<'_>,
+        entry: &Entry<X>,
+        fmt_index: impl Fn(&X, usize, &mut Formatter<'_>) -> core::fmt::Result,
+        fmt_elem: impl Fn(&X, &mut Formatter<'_>) -> core::fmt::Result,
+    ) -> Result<usize, core::fmt::Error> {
+        let mut counter = Counter(0);
+        match entry {
+            Entry::Element(index, elem) => {
+                write!(
+                    counter,
+                    "{}",
+                    Adapter(|f: &mut Formatter<'_>| fmt_index(elem, *index, f))
+                )?;
+                Spec::of(f).write(&mut counter, |f| fmt_elem(elem, f))?
+            }
+            Entry::Omitted(omitted) => self.fmt_placeholder(&mut counter, *omitted)?,
+        }
+
+        Ok(counter.0)
+    }
+
+    /// Writes every item of `items` with `fmt_elem`, surrounded by the
+    /// configured punctuation.
+    pub(crate) fn fmt_iter<I: Iterator + Clone>(
+        &self,
+        f: &mut Formatter<'_>,
+        items: I,
+        fmt_elem: impl Fn(&I::Item, &mut Formatter<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        self.fmt_iter_weighted(
+            f,
+            items,
+            |_| 1,
+            |_, index, f| self.fmt_index(f, index),
+            fmt_elem,
+        )
+    }
+
+    /// Like [`fmt_iter`](Self::fmt_iter), for items that each stand for
+    /// `weight(item)` elements, as counted by the truncation placeholder.
+    ///
+    /// `fmt_index` writes the index of an item from its position, ahead of
+    /// the item and outside of the style of the elements.
+    pub(crate) fn fmt_iter_weighted<I: Iterator + Clone>(
+        &self,
+        f: &mut Formatter<'_>,
+        items: I,
+        weight: impl Fn(&I::Item) -> usize,
+        fmt_index: impl Fn(&I::Item, usize, &mut Formatter<'_>) -> core::fmt::Result,
+        fmt_elem: impl Fn(&I::Item, &mut Formatter<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        let (beginning, ending) = self.terminators;
+        let delimiter = self.delimiter;
+        let spacing = if self.should_space { " " } else { "" };
+        let pretty = self.pretty || f.alternate();
+        let indent = self.indent;
+        let wrap = self.wrap.filter(|_| !pretty);
+        let mut entries = self.entries(items, weight).peekable();
+
+        self.paint(f, Part::Terminators, |f| write!(f, "{beginning}"))?;
+        let mut column = width_of(format_args!("{beginning}"));
+        let mut written = 0;
+        while let Some(entry) = entries.next() {
+            let last = entries.peek().is_none();
+            let conjunction = self
+                .conjunction
+                .filter(|_| last && written > 0 && matches!(entry, Entry::Element(..)));
+            let mut grouped = match (&entry, self.group) {
+                (Entry::Element(index, _), Some(size)) if !pretty => {
+                    written > 0 && index % size == 0
+                }
+                _ => false,
+            };
+
+            if pretty {
+                if written > 0 {
+                    self.paint(f, Part::Delimiters, |f| write!(f, "{delimiter}"))?;
+                }
+                write!(f, "\n{:indent$}", "")?;
+            } else if written > 0 {
+                if conjunction.is_none() || (written > 1 && self.oxford_comma) {
+                    self.paint(f, Part::Delimiters, |f| write!(f, "{delimiter}"))?;
+                    column += width_of(format_args!("{delimiter}"));
+                }
+
+                let gap = if conjunction.is_some() { " " } else { spacing };
+                match wrap {
+                    Some(max) => {
+                        let width = gap.len()
+                            + conjunction
+                                .map_or(0, |conjunction| width_of(format_args!("{conjunction} ")))
+                            + usize::from(grouped)
+                            + self.entry_width(f, &entry, &fmt_index, &fmt_elem)?;
+
+                        let trailing = if last {
+                            width_of(format_args!("{ending}"))
+                        } else {
+                            width_of(format_args!("{delimiter}"))
+                        };
+
+                        if column + width + trailing > max {
+                            write!(f, "\n{:indent$}", "")?;
+                            column = indent + width - gap.len() - usize::from(grouped);
+                            grouped = false;
+                        } else {
+                            f.write_str(gap)?;
+                            column += width;
+                        }
+                    }
+                    None => f.write_str(gap)?,
+                }
+            } else if wrap.is_some() {
+                column += self.entry_width(f, &entry, &fmt_index, &fmt_elem)?;
+            }
+            if let Some(conjunction) = conjunction {
+                write!(f, "{conjunction} ")?;
+            }
+            if grouped {
+                f.write_char(' ')?;
+            }
+
+            if let Entry::Element(index, elem) = &entry {
+                fmt_index(elem, *index, f)?;
+            }
+            match entry {
+                Entry::Element(_, elem) if pretty => self.paint(f, Part::Elements, |f| {
+                    let spec = Spec::of(f);
+                    spec.write(&mut Indented::new(f, indent), |f| fmt_elem(&elem, f))
+                })?,
+                Entry::Element(_, elem) => self.paint(f, Part::Elements, |f| fmt_elem(&elem, f))?,
+                Entry::Omitted(omitted) => self.fmt_placeholder(f, omitted)?,
+            }
+            written += 1;
+        }
+        if pretty && written > 0 {
+            if self.trailing_delimiter {
+                self.paint(f, Part::Delimiters, |f| write!(f, "{delimiter}"))?;
+            }
+            f.write_char('\n')?;
+        }
+
+        self.paint(f, Part::Terminators, |f| write!(f, "{ending}"))
+    }
+
+    /// Writes every element of `slice` with `format`, styled by `paint`.
+    #[cfg(feature = "ansi")]
+    pub(crate) fn fmt_slice<T, F: ElementFormat<T>>(
+        &self,
+        slice: &[T],
+        format: &F,
+        paint: Option<PaintFn<'_, T>>,
+        f: &mut Formatter<'_>,
+    ) -> core::fmt::Result {
+        self.fmt_iter(f, slice.iter(), |elem, f| {
+            let ansi = paint.and_then(|paint| paint(elem));
+            self.paint_with(f, ansi, |f| format.fmt_element(elem, f))
+        })
+    }
+
+    /// Writes every element of `slice` with `format`.
+    #[cfg(not(feature = "ansi"))]
+    pub(crate) fn fmt_slice<T, F: ElementFormat<T>>(
+        &self,
+        slice: &[T],
+        format: &F,
+        f: &mut Formatter<'_>,
+    ) -> core::fmt::Result {
+        self.fmt_iter(f, slice.iter(), |elem, f| format.fmt_element(elem, f))
+    }
+}
+
+/// Counts the characters of `args` once formatted.
+fn width_of(args: core::fmt::Arguments<'_>) -> usize {
+    let mut counter = Counter(0);
+    let _ = counter.write_fmt(args);
+    counter.0
+}
+
+/// Writes `n` with its digits grouped in threes, e.g. `9_996`.
+pub(crate) fn write_grouped(f: &mut impl Write, n: usize) -> core::fmt::Result {
+    if n < 1000 {
+        return write!(f, "{n}");
+    }
+
+    write_grouped(f, n / 1000)?;
+    write!(f, "_{:03}", n % 1000)
+}
@@ -0,0 +1,396 @@
+use core::fmt::{Alignment, Display, Formatter, Write};
+
+use crate::spec::{Adapter, Counter};
+
+/// A column of a [`Table`], made of a header and an accessor writing the
+/// cell of each row.
+pub struct Column<'a, T> {
+    header: &'a str,
+    accessor: &'a dyn Fn(&T, &mut Formatter<'_>) -> core::fmt::Result,
+    align: Alignment,
+}
+
+impl<'a, T> Column<'a, T> {
+    /// Creates a column titled `header`, whose cells are written by `accessor`.
+    pub fn new(
+        header: &'a str,
+        accessor: &'a dyn Fn(&T, &mut Formatter<'_>) -> core::fmt::Result,
+    ) -> Self {
+        Self {
+            header,
+            accessor,
+            align: Alignment::Left,
+        }
+    }
+
+    /// Configures how the header and cells are aligned within the column.
+    ///
+    /// [`Alignment::Left`] by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::fmt::Alignment;
+    ///
+    /// use slicedisplay::{Border, Column, Table};
+    ///
+    /// let prices = [1.5, 12.25];
+    /// let price = |price: &f64, f: &mut std::fmt::Formatter<'_>| write!(f, "{price:.2}");
+    /// let columns = [Column::new("Price", &price).align(Alignment::Right)];
+    ///
+    /// assert_eq!(
+    ///     Table::new(&prices, columns).border(Border::None).to_string(),
+    ///     "Price\n 1.50\n12.25"
+    /// );
+    /// ```
+    pub fn align(self, align: Alignment) -> Self {
+        Self { align, ..self }
+    }
+}
+
+impl<T> Clone for Column<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Column<'_, T> {}
+
+/// The characters used to draw the borders of a [`Table`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Border {
+    /// No borders, columns are only separated by their padding, or a single
+    /// space without padding.
+    None,
+    /// Borders drawn with `+`, `-` and `|`.
+    Ascii,
+    /// Borders drawn with box-drawing characters.
+    Unicode,
+}
+
+/// Which horizontal rule of the table is being drawn.
+#[derive(Clone, Copy)]
+enum Rule {
+    Top,
+    Header,
+    Bottom,
+}
+
+impl Border {
+    fn vertical(self) -> char {
+        match self {
+            Border::None => ' ',
+            Border::Ascii => '|',
+            Border::Unicode => '│',
+        }
+    }
+
+    /// The horizontal line, followed by the left, middle and right junctions.
+    fn rule(self, rule: Rule) -> (char, [char; 3]) {
+        match (self, rule) {
+            (Border::None, _) => (' ', [' '; 3]),
+            (Border::Ascii, _) => ('-', ['+'; 3]),
+            (Border::Unicode, Rule::Top) => ('─', ['┌', '┬', '┐']),
+            (Border::Unicode, Rule::Header) => ('─', ['├', '┼', '┤']),
+            (Border::Unicode, Rule::Bottom) => ('─', ['└', '┴', '┘']),
+        }
+    }
+}
+
+/// Displays a slice of records as an aligned table, one row per element.
+///
+/// Column widths are measured before writing, so nothing is allocated.
+///
+/// # Example
+///
+/// ```rust
+/// use std::fmt::Alignment;
+///
+/// use slicedisplay::{Column, Table};
+///
+/// struct Person {
+///     name: &'static str,
+///     age: u8,
+/// }
+///
+/// let people = [Person { name: "Ana", age: 31 }, Person { name: "Bartholomew", age: 7 }];
+/// let name = |person: &Person, f: &mut std::fmt::Formatter<'_>| f.write_str(person.name);
+/// let age = |person: &Person, f: &mut std::fmt::Formatter<'_>| write!(f, "{}", person.age);
+///
+/// let table = Table::new(&people, [
+///     Column::new("Name", &name),
+///     Column::new("Age", &age).align(Alignment::Right),
+/// ]);
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+-------------+-----+\n\
+///      | Name        | Age |\n\
+///      +-------------+-----+\n\
+///      | Ana         |  31 |\n\
+///      | Bartholomew |   7 |\n\
+///      +-------------+-----+"
+/// );
+/// ```
+pub struct Table<'a, T, const N: usize> {
+    rows: &'a [T],
+    columns: [Column<'a, T>; N],
+    border: Border,
+    padding: usize,
+}
+
+impl<'a, T, const N: usize> Table<'a, T, N> {
+    /// Creates a table with a row for each element of `rows`.
+    pub fn new(rows: &'a [T], columns: [Column<'a, T>; N]) -> Self {
+        Self {
+            rows,
+            columns,
+            border: Border::Ascii,
+            padding: 1,
+        }
+    }
+
+    /// Configures the borders of the table.
+    ///
+    /// [`Border::Ascii`] by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::{Border, Column, Table};
+    ///
+    /// let numbers = [1, 22];
+    /// let value = |n: &i32, f: &mut std::fmt::Formatter<'_>| write!(f, "{n}");
+    /// let square = |n: &i32, f: &mut std::fmt::Formatter<'_>| write!(f, "{}", n * n);
+    /// let columns = [Column::new("n", &value), Column::new("n²", &square)];
+    ///
+    /// assert_eq!(
+    ///     Table::new(&numbers, columns).border(Border::Unicode).to_string(),
+    ///     "┌────┬─────┐\n\
+    ///      │ n  │ n²  │\n\
+    ///      ├────┼─────┤\n\
+    ///      │ 1  │ 1   │\n\
+    ///      │ 22 │ 484 │\n\
+    ///      └────┴─────┘"
+    /// );
+    /// assert_eq!(
+    ///     Table::new(&numbers, columns).border(Border::None).to_string(),
+    ///     "n   n²\n1   1\n22  484"
+    /// );
+    /// ```
+    pub fn border(self, border: Border) -> Self {
+        Self { border, ..self }
+    }
+
+    /// Configures the amount of spaces around the content of each cell.
+    ///
+    /// 1 by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slicedisplay::{Border, Column, Table};
+    ///
+    /// let pairs = [("a", 1), ("bc", 2)];
+    /// let key = |pair: &(&str, i32), f: &mut std::fmt::Formatter<'_>| f.write_str(pair.0);
+    /// let value = |pair: &(&str, i32), f: &mut std::fmt::Formatter<'_>| write!(f, "{}", pair.1);
+    /// let columns = [Column::new("k", &key), Column::new("v", &value)];
+    ///
+    /// assert_eq!(
+    ///     Table::new(&pairs, columns).padding(0).to_string(),
+    ///     "+--+-+\n\
+    ///      |k |v|\n\
+    ///      +--+-+\n\
+    ///      |a |1|\n\
+    ///      |bc|2|\n\
+    ///      +--+-+"
+    /// );
+    /// assert_eq!(
+    ///     Table::new(&pairs, columns).border(Border::None).padding(0).to_string(),
+    ///     "k  v\na  1\nbc 2"
+    /// );
+    /// ```
+    pub fn padding(self, padding: usize) -> Self {
+        Self { padding, ..self }
+    }
+
+    fn widths(&self) -> Result<[usize; N], core::fmt::Error> {
+        let mut widths = [0; N];
+        for (width, column) in widths.iter_mut().zip(&self.columns) {
+            *width = column.header.chars().count();
+            for row in self.rows {
+                *width = (*width).max(cell_width(column, row)?);
+            }
+        }
+
+        Ok(widths)
+    }
+
+    fn fmt_rule(
+        &self,
+        f: &mut Formatter<'_>,
+        widths: &[usize; N],
+        rule: Rule,
+    ) -> core::fmt::Result {
+        let (line, [left, middle, right]) = self.border.rule(rule);
+
+        f.write_char(left)?;
+        for (index, width) in widths.iter().enumerate() {
+            if index > 0 {
+                f.write_char(middle)?;
+            }
+            fill(f, line, width + 2 * self.padding)?;
+        }
+        f.write_char(right)
+    }
+
+    /// Writes a line of cells, `cell` writing the content of each column.
+    fn fmt_line(
+        &self,
+        f: &mut Formatter<'_>,
+        widths: &[usize; N],
+        cell: impl Fn(&Column<'a, T>, &mut Formatter<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        let bordered = self.border != Border::None;
+        let vertical = self.border.vertical();
+
+        if bordered {
+            f.write_char(vertical)?;
+        }
+        for (index, (column, width)) in self.columns.iter().zip(widths).enumerate() {
+            if index > 0 && bordered {
+                f.write_char(vertical)?;
+            }
+            if bordered {
+                fill(f, ' ', self.padding)?;
+            } else if index > 0 {
+                // Without a border, columns are kept at least a space apart.
+                fill(f, ' ', self.padding.max(1))?;
+            }
+
+            let mut counter = Counter(0);
+            write!(
+                counter,
+                "{}",
+                Adapter(|f: &mut Formatter<'_>| cell(column, f))
+            )?;
+            let padding = width.saturating_sub(counter.0);
+            let (before, after) = match column.align {
+                Alignment::Left => (0, padding),
+                Alignment::Center => (padding / 2, padding - padding / 2),
+                Alignment::Right => (padding, 0),
+            };
+
+            fill(f, ' ', before)?;
+            cell(column, f)?;
+            // Without a border, the last cell is not padded so that lines
+            // have no trailing whitespace.
+            if bordered || index + 1 < N {
+                fill(f, ' ', after)?;
+                fill(f, ' ', self.padding)?;
+            }
+        }
+        if bordered {
+            f.write_char(vertical)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, const N: usize> Display for Table<'_, T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let bordered = self.border != Border::None;
+        let widths = self.widths()?;
+
+        if bordered {
+            self.fmt_rule(f, &widths, Rule::Top)?;
+            f.write_char('\n')?;
+        }
+        self.fmt_line(f, &widths, |column, f| f.write_str(column.header))?;
+        if bordered {
+            f.write_char('\n')?;
+            self.fmt_rule(f, &widths, Rule::Header)?;
+        }
+        for row in self.rows {
+            f.write_char('\n')?;
+            self.fmt_line(f, &widths, |column, f| (column.accessor)(row, f))?;
+        }
+        if bordered {
+            f.write_char('\n')?;
+            self.fmt_rule(f, &widths, Rule::Bottom)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn cell_width<T>(column: &Column<'_, T>, row: &T) -> Result<usize, core::fmt::Error> {
+    let mut counter = Counter(0);
+    write!(
+        counter,
+        "{}",
+        Adapter(|f: &mut Formatter<'_>| (column.accessor)(row, f))
+    )?;
+
+    Ok(counter.0)
+}
+
+fn fill(f: &mut Formatter<'_>, c: char, count: usize) -> core::fmt::Result {
+    (0..count).try_for_each(|_| f.write_char(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use core::fmt::{Alignment, Formatter};
+
+    use crate::{Border, Column, Table};
+
+    extern crate alloc;
+
+    #[test]
+    fn table_display() {
+        let rows = [("apple", 1.5), ("fig", 12.25)];
+        let name = |row: &(&str, f64), f: &mut Formatter<'_>| f.write_str(row.0);
+        let price = |row: &(&str, f64), f: &mut Formatter<'_>| write!(f, "{:.2}", row.1);
+        let columns = [
+            Column::new("Fruit", &name).align(Alignment::Center),
+            Column::new("Price", &price).align(Alignment::Right),
+        ];
+
+        assert_eq!(
+            Table::new(&rows, columns).padding(0).to_string(),
+            "+-----+-----+\n\
+             |Fruit|Price|\n\
+             +-----+-----+\n\
+             |apple| 1.50|\n\
+             | fig |12.25|\n\
+             +-----+-----+"
+        );
+
+        let empty: [(&str, f64); 0] = [];
+        assert_eq!(
+            Table::new(&empty, columns)
+                .border(Border::Unicode)
+                .to_string(),
+            "┌───────┬───────┐\n\
+             │ Fruit │ Price │\n\
+             ├───────┼───────┤\n\
+             └───────┴───────┘"
+        );
+
+        let columns = [columns[1], columns[0]];
+        assert_eq!(
+            Table::new(&rows, columns).border(Border::None).to_string(),
+            "Price  Fruit\n 1.50  apple\n12.25   fig"
+        );
+        assert_eq!(
+            Table::new(&rows, columns)
+                .border(Border::None)
+                .padding(0)
+                .to_string(),
+            "Price Fruit\n 1.50 apple\n12.25  fig"
+        );
+    }
+}